@@ -0,0 +1,174 @@
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use crate::prelude::*;
+
+/// Object-safe interner used by `BoxedInterner` to erase the type of the inner interner,
+///
+trait DynInterner: Send {
+    /// Pushes a tag w/ the bytes written by the tag value's `Hash` impl,
+    ///
+    fn push_tag_bytes(&mut self, bytes: HashedBytes, assign: InternHandleThunk);
+
+    /// Pushes a tag w/ a precomputed hash of the tag value,
+    ///
+    fn push_tag_prehashed(&mut self, precomputed: u64, assign: InternHandleThunk);
+
+    /// Sets the current level flags for the interner,
+    ///
+    fn set_level_flags(&mut self, flags: LevelFlags);
+
+    /// Sets the current data value for the interner,
+    ///
+    fn set_data(&mut self, data: u64);
+
+    /// Finishes generating the current intern handle,
+    ///
+    fn interner(&mut self) -> InternResult;
+
+    /// Returns the name of the family of the interner,
+    ///
+    fn family(&self) -> &'static str;
+}
+
+impl<I: InternerFactory + Send> DynInterner for I {
+    fn push_tag_bytes(&mut self, bytes: HashedBytes, assign: InternHandleThunk) {
+        self.push_tag(bytes, assign);
+    }
+
+    fn push_tag_prehashed(&mut self, precomputed: u64, assign: InternHandleThunk) {
+        InternerFactory::push_tag_prehashed(self, precomputed, assign);
+    }
+
+    fn set_level_flags(&mut self, flags: LevelFlags) {
+        InternerFactory::set_level_flags(self, flags);
+    }
+
+    fn set_data(&mut self, data: u64) {
+        InternerFactory::set_data(self, data);
+    }
+
+    fn interner(&mut self) -> InternResult {
+        InternerFactory::interner(self)
+    }
+
+    fn family(&self) -> &'static str {
+        InternerFactory::family(self)
+    }
+}
+
+/// Bytes written by the `Hash` impl of a tag value,
+///
+/// **Note** Hashing this value writes the recorded bytes as is, so the inner interner hashes the same bytes it would
+/// have hashed from the original tag value.
+///
+#[derive(Default)]
+pub(crate) struct HashedBytes(Vec<u8>);
+
+impl Hasher for HashedBytes {
+    fn finish(&self) -> u64 {
+        xxhash_rust::xxh3::xxh3_64(&self.0)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+}
+
+impl Hash for HashedBytes {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write(&self.0);
+    }
+}
+
+/// Interner that boxes an inner interner, so that the interner can be selected at runtime,
+///
+/// **Note** Handles created by a boxed interner are identical to the handles created by the inner interner, and the
+/// family of the inner interner is recorded for each level.
+///
+pub struct BoxedInterner {
+    /// Inner interner,
+    ///
+    inner: Box<dyn DynInterner>,
+}
+
+impl BoxedInterner {
+    /// Returns a new boxed interner w/ an inner interner,
+    ///
+    pub fn new(interner: impl InternerFactory + Send + 'static) -> Self {
+        Self {
+            inner: Box::new(interner),
+        }
+    }
+
+    /// Returns a new boxed interner for an interner name,
+    ///
+    /// | Name         | Interner                       |
+    /// | ------------ | ------------------------------ |
+    /// | `crc`        | `CrcInterner`                  |
+    /// | `entity-crc` | `EntityInterner<CrcInterner>`  |
+    ///
+    /// **Error** Returns an error if the name is not a known interner.
+    ///
+    #[cfg(feature = "crc-interner")]
+    pub fn from_name(name: &str) -> anyhow::Result<Self> {
+        Self::named_factory(name).map(|f| f()).ok_or_else(|| {
+            anyhow::anyhow!("Unknown interner {name}, expected one of crc, entity-crc")
+        })
+    }
+
+    /// Returns a function that creates a new boxed interner for an interner name, see `BoxedInterner::from_name`,
+    ///
+    #[cfg(feature = "crc-interner")]
+    pub(crate) fn named_factory(name: &str) -> Option<fn() -> Self> {
+        match name {
+            "crc" => Some(|| Self::new(CrcInterner::default())),
+            "entity-crc" => Some(|| Self::new(EntityInterner::<CrcInterner>::default())),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "crc-interner")]
+impl Default for BoxedInterner {
+    fn default() -> Self {
+        Self::new(CrcInterner::default())
+    }
+}
+
+impl InternerFactory for BoxedInterner {
+    fn push_tag<T: Hash + Send + Sync + 'static>(
+        &mut self,
+        value: T,
+        assign: impl Fn(InternHandle) -> anyhow::Result<()> + Send + Sync + 'static,
+    ) {
+        let mut bytes = HashedBytes::default();
+        value.hash(&mut bytes);
+
+        self.inner.push_tag_bytes(bytes, Box::new(assign));
+    }
+
+    fn push_tag_prehashed(
+        &mut self,
+        precomputed: u64,
+        assign: impl Fn(InternHandle) -> anyhow::Result<()> + Send + Sync + 'static,
+    ) {
+        self.inner.push_tag_prehashed(precomputed, Box::new(assign));
+    }
+
+    fn set_level_flags(&mut self, flags: LevelFlags) {
+        self.inner.set_level_flags(flags);
+    }
+
+    fn set_data(&mut self, data: u64) {
+        self.inner.set_data(data);
+    }
+
+    fn interner(&mut self) -> InternResult {
+        self.inner.interner()
+    }
+
+    fn family(&self) -> &'static str {
+        self.inner.family()
+    }
+}
@@ -6,6 +6,7 @@ use crc::Crc;
 use std::cell::RefCell;
 use std::hash::Hash;
 use std::hash::Hasher;
+use std::sync::Mutex;
 use std::sync::OnceLock;
 use tracing::trace;
 
@@ -53,6 +54,90 @@ impl CrcInterner {
     }
 }
 
+impl CrcInterner {
+    /// Returns the hash of the tags pushed so far w/o finalizing the digest,
+    ///
+    /// This is useful when debugging why two handles collide, since the hash can be inspected after each tag is
+    /// pushed to find the tag that caused the handles to converge.
+    ///
+    /// **Note** The digest is copied before it is finalized, so the pending tags and the handle returned by
+    /// `interner()` are unchanged.
+    ///
+    pub fn peek_hash(&self) -> u64 {
+        digest_key(self.digest.borrow().clone().finalize())
+    }
+
+    /// Resets the digest and pending state so that this interner can be reused,
+    ///
+    /// **Note** The existing digest cell is reused, so the interner will produce the same handles as a
+    /// freshly created interner.
+    ///
+    pub fn reset_digest(&mut self) {
+        let crc = INTERNER_CRC.get_or_init(|| Crc::<u32>::new(&crc::CRC_24_OPENPGP));
+
+        *self.digest.get_mut() = crc.digest();
+        self.tags.clear();
+        self.flags = LevelFlags::ROOT;
+        self.data = 0;
+    }
+}
+
+/// Pool of crc interners that can be reused between links,
+///
+/// **Example**
+///
+/// ```rs no_run
+/// let pool = CrcInternerPool::default();
+///
+/// // Take an interner from the pool, or create a new one if the pool is empty
+/// let mut interner = pool.take();
+/// let handle = level.configure(&mut interner)?;
+///
+/// // Return the interner so it can be reused by the next caller
+/// pool.recycle(interner);
+/// ```
+///
+#[derive(Default)]
+pub struct CrcInternerPool {
+    /// Interners available for reuse,
+    ///
+    pool: Mutex<Vec<CrcInterner>>,
+}
+
+impl CrcInternerPool {
+    /// Takes an interner from the pool, creating a new interner if the pool is empty,
+    ///
+    pub fn take(&self) -> CrcInterner {
+        self.pool
+            .lock()
+            .ok()
+            .and_then(|mut p| p.pop())
+            .unwrap_or_default()
+    }
+
+    /// Resets an interner and returns it to the pool,
+    ///
+    pub fn recycle(&self, mut interner: CrcInterner) {
+        interner.reset_digest();
+
+        if let Ok(mut pool) = self.pool.lock() {
+            pool.push(interner);
+        }
+    }
+
+    /// Returns the number of interners available for reuse,
+    ///
+    pub fn len(&self) -> usize {
+        self.pool.lock().map(|p| p.len()).unwrap_or_default()
+    }
+
+    /// Returns true if there are no interners available for reuse,
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
 impl InternerFactory for CrcInterner {
     #[inline]
     fn push_tag<T>(
@@ -102,23 +187,32 @@ impl InternerFactory for CrcInterner {
             (tag)(handle)?
         }
 
+        #[cfg(feature = "observe")]
+        crate::interner::notify_handle_created(handle);
+
         Ok(handle)
     }
 }
 
+/// Converts a finalized crc digest into the hash used to create an intern handle,
+///
+fn digest_key(hash: u32) -> u64 {
+    let [lo, hi] = bytemuck::cast::<u32, [u16; 2]>(hash);
+
+    let uuid = uuid::Uuid::from_fields(0, hi, lo, &[0; 8]);
+
+    let (key, _) = uuid.as_u64_pair();
+
+    key
+}
+
 impl Hasher for CrcInterner {
     fn finish(&self) -> u64 {
         let crc = INTERNER_CRC.get_or_init(|| Crc::<u32>::new(&crc::CRC_24_OPENPGP));
 
         let hash = self.digest.replace(crc.digest()).finalize();
 
-        let [lo, hi] = bytemuck::cast::<u32, [u16; 2]>(hash);
-
-        let uuid = uuid::Uuid::from_fields(0, hi, lo, &[0; 8]);
-
-        let (key, _) = uuid.as_u64_pair();
-
-        key
+        digest_key(hash)
     }
 
     fn write(&mut self, bytes: &[u8]) {
@@ -211,8 +305,54 @@ mod tests {
         assert_eq!(a, b);
 
         let address = handle.host_address();
+    }
 
-        ()
+    #[test]
+    fn test_with_level() {
+        let mut interner = CrcInterner::new();
+
+        let handle = FieldLevel::new::<0, Test>()
+            .configure(&mut interner)
+            .unwrap();
+        assert_eq!(LevelFlags::LEVEL_1, handle.level_flags());
+
+        let root = handle.with_level(LevelFlags::ROOT);
+        assert_eq!(LevelFlags::ROOT, root.level_flags());
+        assert_eq!(handle.register_hi & 0x00FF, root.register_hi & 0x00FF);
+        assert_eq!(handle.register_lo, root.register_lo);
+        assert_eq!(handle.link, root.link);
+        assert_eq!(handle.data, root.data);
+
+        assert_eq!(handle, root.with_level(LevelFlags::LEVEL_1));
+
+        assert!(crate::repr::field::FIELD_NAME.contains(&handle));
+        assert!(!crate::repr::field::FIELD_NAME.contains(&root));
+        assert!(crate::repr::field::FIELD_NAME.contains(&root.with_level(LevelFlags::LEVEL_1)));
+    }
+
+    #[test]
+    fn test_interner_pool() {
+        let fresh = FieldLevel::new::<0, Test>()
+            .configure(&mut CrcInterner::new())
+            .unwrap();
+
+        let pool = CrcInternerPool::default();
+        let mut interner = pool.take();
+
+        // Leave pending state on the interner before recycling it
+        interner.set_level_flags(LevelFlags::LEVEL_3);
+        interner.set_data(1);
+        std::hash::Hash::hash("pending", &mut interner);
+        pool.recycle(interner);
+        assert_eq!(1, pool.len());
+
+        let mut recycled = pool.take();
+        assert!(pool.is_empty());
+
+        let handle = FieldLevel::new::<0, Test>()
+            .configure(&mut recycled)
+            .unwrap();
+        assert_eq!(fresh, handle);
     }
 
     #[test]
@@ -274,12 +414,65 @@ mod tests {
         let parent_type_name = parent.unwrap().as_resource().unwrap().type_name().unwrap();
         eprintln!("{}", parent_type_name);
 
-        let before_upgrade = _drepr.clone();
+        let before_upgrade = _drepr;
         let upgrade = NodeLevel::new().with_input("hello world");
         _drepr.upgrade(CrcInterner::default(), upgrade).unwrap();
 
         let input = _drepr.as_node().unwrap().input().unwrap();
         eprintln!("{:?}", input);
-        ()
+    }
+
+    #[test]
+    #[cfg(feature = "observe")]
+    fn test_on_handle_created() {
+        use std::sync::Arc;
+        use std::sync::Mutex;
+
+        struct Observed;
+
+        let observed = Arc::new(Mutex::new(vec![]));
+        let inner = observed.clone();
+        crate::interner::on_handle_created(move |h| inner.lock().unwrap().push(h));
+
+        let mut linker = Linker::new_crc::<Observed>();
+        linker.push_level(DependencyLevel::new("observed")).unwrap();
+        let repr = linker.link().unwrap();
+
+        // Handles computed by a dry run are not observed, only the handle configured when the level was pushed
+        let mut dry = Linker::new_crc::<Observed>();
+        dry.push_level(DependencyLevel::new("observed dry run"))
+            .unwrap();
+        let (_, dry) = dry.dry_run().unwrap().tail.node();
+
+        // Other tests may create handles concurrently, so only check that each level was observed
+        let observed = observed.lock().unwrap();
+        for level in repr.get_levels() {
+            assert!(observed.contains(&level));
+        }
+        assert_eq!(1, observed.iter().filter(|h| **h == dry).count());
+    }
+
+    #[test]
+    fn test_peek_hash() {
+        let empty = CrcInterner::new();
+        let empty_hash = empty.peek_hash();
+
+        let mut interner = CrcInterner::new();
+        interner.push_tag("peek", |_| Ok(()));
+        let first = interner.peek_hash();
+        assert_ne!(empty_hash, first);
+
+        interner.push_tag(2518u64, |_| Ok(()));
+        let second = interner.peek_hash();
+        assert_ne!(first, second);
+
+        // Peeking does not consume the digest
+        assert_eq!(second, interner.peek_hash());
+
+        let mut expected = CrcInterner::new();
+        expected.push_tag("peek", |_| Ok(()));
+        expected.push_tag(2518u64, |_| Ok(()));
+        assert_eq!(second, expected.peek_hash());
+        assert_eq!(expected.interner().unwrap(), interner.interner().unwrap());
     }
 }
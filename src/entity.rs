@@ -1,3 +1,6 @@
+use std::ops::Range;
+
+use anyhow::anyhow;
 use tracing::warn;
 
 use crate::{define_intern_table, prelude::*};
@@ -12,6 +15,79 @@ pub struct EntityInterner<Inner: InternerFactory> {
 
 define_intern_table!(ENTITY: u64);
 
+impl<Inner: InternerFactory> EntityInterner<Inner> {
+    /// Reserves a contiguous block of entity ids and returns the reserved range,
+    ///
+    /// The ids in the range are not assigned to any handle, the caller is responsible for minting them w/
+    /// `mint_reserved`. Entities minted by `interner()` afterwards continue after the reserved range.
+    ///
+    /// **Note** Entity 0 is reserved and is never included in the range.
+    ///
+    /// **Error** Returns an error if the end of the range would overflow, no ids are reserved in that case.
+    ///
+    pub fn reserve_ids(&mut self, count: u64) -> anyhow::Result<Range<u64>> {
+        let Some(end) = self
+            .counter
+            .checked_add(count)
+            .and_then(|counter| counter.checked_add(1))
+        else {
+            return Err(anyhow!(
+                "Cannot reserve {count} entity ids after entity {}",
+                self.counter
+            ));
+        };
+
+        let start = self.counter + 1;
+        self.counter = end - 1;
+        Ok(start..end)
+    }
+
+    /// Finishes generating the current intern handle w/ an entity id reserved w/ `reserve_ids`,
+    ///
+    /// **Error** Returns an error if the id has not been reserved by this interner.
+    ///
+    pub fn mint_reserved(&mut self, id: u64) -> InternResult {
+        self.check_id(id)?;
+
+        self.inner.set_data(id);
+        let handle = self.inner.interner()?;
+
+        self.register_id(id, handle)?;
+
+        Ok(handle)
+    }
+
+    /// Registers an entity id reserved w/ `reserve_ids` for an intern handle,
+    ///
+    /// **Note** The data value of the handle must be the entity id, since `InternHandle::entity` only returns the
+    /// entity id of a handle that carries it, i.e. a handle created by `mint_reserved`.
+    ///
+    /// **Error** Returns an error if the id has not been reserved or minted by this interner, or if the handle does not
+    /// carry the id.
+    ///
+    pub fn register_id(&self, id: u64, handle: InternHandle) -> anyhow::Result<()> {
+        self.check_id(id)?;
+
+        if handle.data() != id {
+            return Err(anyhow!("Handle {handle:?} does not carry entity id {id}"));
+        }
+
+        ENTITY.assign_intern(handle, id)
+    }
+
+    /// Checks that an entity id has been reserved or minted by this interner,
+    ///
+    fn check_id(&self, id: u64) -> anyhow::Result<()> {
+        if id == 0 || id > self.counter {
+            return Err(anyhow!(
+                "Entity id {id} has not been reserved by this interner"
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 impl<Inner: InternerFactory> InternerFactory for EntityInterner<Inner> {
     fn push_tag<T: std::hash::Hash + Send + Sync + 'static>(
         &mut self,
@@ -40,3 +116,49 @@ impl<Inner: InternerFactory> InternerFactory for EntityInterner<Inner> {
         Ok(result)
     }
 }
+
+#[allow(unused)]
+mod tests {
+    use crate::prelude::*;
+
+    use super::ENTITY;
+
+    #[test]
+    fn test_reserve_ids() {
+        let mut interner = EntityInterner::<CrcInterner>::default();
+
+        let reserved = interner.reserve_ids(100).unwrap();
+        assert_eq!(1..101, reserved);
+        assert_eq!(100, reserved.clone().count());
+        assert!(reserved
+            .clone()
+            .zip(reserved.clone().skip(1))
+            .all(|(a, b)| b == a + 1));
+
+        interner.push_tag("test_reserve_ids".to_string(), |_| Ok(()));
+        interner.set_level_flags(LevelFlags::ROOT);
+        let minted = interner.interner().unwrap();
+        assert_eq!(Some(101), ENTITY.copy(&minted));
+
+        interner.push_tag("test_reserve_ids reserved".to_string(), |_| Ok(()));
+        interner.set_level_flags(LevelFlags::ROOT);
+        let reserved = interner.mint_reserved(42).unwrap();
+        assert_eq!(Some(42), ENTITY.copy(&reserved));
+        assert_eq!(Some(42), reserved.entity());
+        assert_eq!(Some(42), Repr { tail: reserved }.entity_id());
+
+        // Handles must carry the id they are registered w/
+        let handle = InternHandle::from_parts(0, 0x0100, 0x5e5e, 0x0250_1000);
+        assert!(interner.register_id(42, handle).is_err());
+        assert!(!ENTITY.contains(&handle));
+
+        assert!(interner.register_id(0, reserved).is_err());
+        assert!(interner.register_id(102, reserved).is_err());
+        assert!(interner.mint_reserved(102).is_err());
+
+        // Reserving past the last id returns an error w/o reserving any ids
+        assert!(interner.reserve_ids(u64::MAX).is_err());
+        assert!(interner.reserve_ids(u64::MAX - 101).is_err());
+        assert_eq!(102..103, interner.reserve_ids(1).unwrap());
+    }
+}
@@ -7,7 +7,7 @@ thread_local!(
     /// happening, which can be the case during unit tests. In that case this can be set before the test
     /// runs and will allow intern handles generated on that thread to be scoped w/ an entropy value.
     ///
-    pub(crate) static ENTROPY: Cell<u64> = Cell::new(0)
+    pub(crate) static ENTROPY: Cell<u64> = const { Cell::new(0) }
 );
 
 /// Initializes a random entropy value for the current thread,
@@ -20,6 +20,30 @@ pub(crate) fn set_entropy() -> u64 {
     e
 }
 
+/// Guard that sets the entropy of the current thread and restores the previous entropy when dropped,
+///
+pub(crate) struct EntropyGuard {
+    /// Entropy value to restore,
+    ///
+    previous: u64,
+}
+
+impl EntropyGuard {
+    /// Sets the entropy of the current thread, returning a guard that restores the previous value,
+    ///
+    pub(crate) fn new(entropy: u64) -> Self {
+        Self {
+            previous: ENTROPY.replace(entropy),
+        }
+    }
+}
+
+impl Drop for EntropyGuard {
+    fn drop(&mut self) {
+        ENTROPY.set(self.previous);
+    }
+}
+
 /// Create a new entropy aware runtime,
 ///
 pub fn new_runtime() -> tokio::runtime::Builder {
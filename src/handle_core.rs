@@ -0,0 +1,412 @@
+//! Pure intern handle arithmetic,
+//!
+//! **Note** This module does not depend on the intern tables, so the handle algebra can be tested w/o linking
+//! any reprs. Operations that depend on the thread-local entropy take the entropy value as a parameter, the entropy
+//! aware wrappers (i.e. `InternHandle::node`) are implemented alongside the intern tables.
+//!
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Handle which can be converted into a 64-bit key,
+///
+#[derive(
+    Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub struct InternHandle {
+    /// Link value,
+    ///
+    pub(crate) link: u32,
+    /// Upper register,
+    ///
+    /// **Note on CrcInterner impl**: The first half of the upper register contains the level bits.
+    ///
+    pub(crate) register_hi: u16,
+    /// Lower register,
+    ///
+    pub(crate) register_lo: u16,
+    /// Data register,
+    ///
+    pub(crate) data: u64,
+}
+
+impl InternHandle {
+    /// Creates an intern handle from explicit field values,
+    ///
+    /// **Note** The data value is stored as is and is not mixed w/ the current entropy.
+    ///
+    #[inline]
+    pub const fn from_parts(link: u32, register_hi: u16, register_lo: u16, data: u64) -> Self {
+        Self {
+            link,
+            register_hi,
+            register_lo,
+            data,
+        }
+    }
+
+    /// Returns the current level flag enabled for this intern handle,
+    ///
+    #[inline]
+    pub fn level_flags(&self) -> LevelFlags {
+        LevelFlags::from_bits_truncate(self.register_hi)
+    }
+
+    /// Returns a copy of this handle w/ the level flags replaced,
+    ///
+    /// The upper byte of `register_hi` holds the level flags, and the lower byte holds the upper bits
+    /// of the content hash. Only the upper byte is replaced, so the content hash portion of the register
+    /// is preserved. This allows probing for the same content at a different level, i.e.
+    /// `FIELD_NAME.contains(&handle.with_level(LevelFlags::LEVEL_1))`.
+    ///
+    #[inline]
+    pub fn with_level(&self, flags: LevelFlags) -> InternHandle {
+        let mut handle = *self;
+        handle.register_hi = (self.register_hi & CONTENT_HASH_MASK) | flags.bits();
+        handle
+    }
+
+    /// Converts the handle to a u64 value,
+    ///
+    /// **Note**: This contains the full handle value, laid out as `link | register_hi | register_lo`.
+    ///
+    #[inline]
+    pub const fn as_u64(&self) -> u64 {
+        (self.link as u64) << 32 | (self.register_hi as u64) << 16 | self.register_lo as u64
+    }
+
+    /// Returns the register value of the current handle,
+    ///
+    #[inline]
+    pub fn register(&self) -> u32 {
+        bytemuck::cast::<[u16; 2], u32>([self.register_lo, self.register_hi])
+    }
+
+    /// Returns true if the current handle is a root handle,
+    ///
+    #[inline]
+    pub fn is_root(&self) -> bool {
+        self.level_flags() == LevelFlags::ROOT
+    }
+
+    /// Returns true if the current handle is a node handle,
+    ///
+    /// **Note** A node handle contains a non-zero link value.
+    ///
+    #[inline]
+    pub fn is_node(&self) -> bool {
+        self.link > 0
+    }
+
+    /// Returns a split view of the current intern handle providing the current and previous nodes,
+    ///
+    /// **Note** The data value of the previous node is set to entropy.
+    ///
+    pub fn node_with_entropy(&self, entropy: u64) -> (Option<InternHandle>, InternHandle) {
+        let prev = self.link ^ self.register();
+
+        let [lo, hi] = bytemuck::cast::<u32, [u16; 2]>(prev);
+
+        let prev_level = LevelFlags::from_bits_truncate(hi);
+
+        let mut prev_handle = None;
+        if prev_level.bits() << 1 == self.level_flags().bits() {
+            let _ = prev_handle.insert(InternHandle {
+                link: 0,
+                register_hi: hi,
+                register_lo: lo,
+                data: entropy,
+            });
+        }
+
+        let mut current = *self;
+        current.link = 0;
+
+        (prev_handle, current)
+    }
+}
+
+/// Canonical intern handle w/o the data register, for keying tables that never use entity data,
+///
+/// **Note** Only an intern handle w/ a canonical data value of 0 (i.e. a data value equal to the entropy it was created
+/// w/) can be converted into a compact handle. The entropy is removed when a handle is compacted and re-applied when the
+/// compact handle is expanded, so crc handles created w/ any entropy can be compacted. Entity handles store an entity
+/// id in the data value and cannot be compacted.
+///
+#[derive(
+    Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub struct CompactHandle {
+    /// Link value,
+    ///
+    pub(crate) link: u32,
+    /// Upper register,
+    ///
+    pub(crate) register_hi: u16,
+    /// Lower register,
+    ///
+    pub(crate) register_lo: u16,
+}
+
+impl CompactHandle {
+    /// Converts the handle to a u64 value, laid out the same as `InternHandle::as_u64`,
+    ///
+    #[inline]
+    pub const fn as_u64(&self) -> u64 {
+        (self.link as u64) << 32 | (self.register_hi as u64) << 16 | self.register_lo as u64
+    }
+
+    /// Returns the compact handle of an intern handle created w/ entropy,
+    ///
+    /// **Error** Returns an error w/ the canonical handle if the canonical data value is not 0.
+    ///
+    #[inline]
+    pub fn from_handle_with_entropy(
+        handle: InternHandle,
+        entropy: u64,
+    ) -> Result<Self, InvalidCompactHandle> {
+        let mut canonical = handle;
+        canonical.data ^= entropy;
+
+        if canonical.data != 0 {
+            return Err(InvalidCompactHandle(canonical));
+        }
+
+        Ok(CompactHandle {
+            link: handle.link,
+            register_hi: handle.register_hi,
+            register_lo: handle.register_lo,
+        })
+    }
+
+    /// Returns the intern handle of this compact handle w/ entropy applied to the data value,
+    ///
+    #[inline]
+    pub const fn into_handle_with_entropy(self, entropy: u64) -> InternHandle {
+        InternHandle::from_parts(self.link, self.register_hi, self.register_lo, entropy)
+    }
+}
+
+/// Error returned when an intern handle w/ a non-zero canonical data value is converted into a compact handle,
+///
+/// **Note** Contains the canonical handle, i.e. the handle w/ the entropy removed from the data value.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidCompactHandle(pub InternHandle);
+
+impl core::fmt::Display for InvalidCompactHandle {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Expected an intern handle w/ a canonical data value of 0, found {:#x}",
+            self.0.data
+        )
+    }
+}
+
+impl core::error::Error for InvalidCompactHandle {}
+
+bitflags::bitflags! {
+    /// Representation level flags,
+    ///
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct LevelFlags : u16 {
+        /// Root representation level
+        ///
+        const ROOT = 0x0100;
+
+        /// Representation Level 1
+        ///
+        const LEVEL_1 = 0x0100 << 1;
+
+        /// Representation Level 2
+        ///
+        const LEVEL_2 = 0x0100 << 2;
+
+        /// Representation Level 3
+        ///
+        const LEVEL_3 = 0x0100 << 3;
+
+        /// Representation level 4
+        ///
+        const LEVEL_4 = 0x0100 << 4;
+
+        /// Representation level 5
+        ///
+        const LEVEL_5 = 0x0100 << 5;
+
+        /// Representation level 6
+        ///
+        const LEVEL_6 = 0x0100 << 6;
+
+        /// Representation level 7
+        ///
+        const LEVEL_7 = 0x0100 << 7;
+    }
+}
+
+/// Bits of `register_hi` allocated to the level flags,
+///
+pub(crate) const LEVEL_FLAGS_MASK: u16 = 0xff00;
+
+/// Bits of `register_hi` allocated to the upper bits of the content hash,
+///
+pub(crate) const CONTENT_HASH_MASK: u16 = 0x00ff;
+
+// Every level flag must fit in the bits allocated to the level flags, a flag past LEVEL_7 would not fit in a u16
+const _: () = assert!(
+    LevelFlags::all().bits() & !LEVEL_FLAGS_MASK == 0,
+    "Level flags must fit in the upper byte of register_hi"
+);
+
+// The content hash portion of register_hi must never overlap the level flags
+const _: () = assert!(
+    LEVEL_FLAGS_MASK & CONTENT_HASH_MASK == 0 && LEVEL_FLAGS_MASK | CONTENT_HASH_MASK == u16::MAX,
+    "Level flags and the content hash must partition register_hi"
+);
+
+/// Enumeration of each single representation level, for exhaustive matching over level flags,
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LevelKind {
+    /// Root representation level,
+    ///
+    Root,
+    /// Representation level 1,
+    ///
+    L1,
+    /// Representation level 2,
+    ///
+    L2,
+    /// Representation level 3,
+    ///
+    L3,
+    /// Representation level 4,
+    ///
+    L4,
+    /// Representation level 5,
+    ///
+    L5,
+    /// Representation level 6,
+    ///
+    L6,
+    /// Representation level 7,
+    ///
+    L7,
+}
+
+impl LevelKind {
+    /// Returns the index of this level, w/ the root level at index 0,
+    ///
+    #[inline]
+    pub const fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+/// Error returned when level flags do not contain exactly one level,
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidLevelFlags(pub LevelFlags);
+
+impl core::fmt::Display for InvalidLevelFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Expected exactly one level flag, found {:#06x}",
+            self.0.bits()
+        )
+    }
+}
+
+impl core::error::Error for InvalidLevelFlags {}
+
+impl TryFrom<LevelFlags> for LevelKind {
+    type Error = InvalidLevelFlags;
+
+    fn try_from(flags: LevelFlags) -> Result<Self, Self::Error> {
+        match flags {
+            LevelFlags::ROOT => Ok(LevelKind::Root),
+            LevelFlags::LEVEL_1 => Ok(LevelKind::L1),
+            LevelFlags::LEVEL_2 => Ok(LevelKind::L2),
+            LevelFlags::LEVEL_3 => Ok(LevelKind::L3),
+            LevelFlags::LEVEL_4 => Ok(LevelKind::L4),
+            LevelFlags::LEVEL_5 => Ok(LevelKind::L5),
+            LevelFlags::LEVEL_6 => Ok(LevelKind::L6),
+            LevelFlags::LEVEL_7 => Ok(LevelKind::L7),
+            _ => Err(InvalidLevelFlags(flags)),
+        }
+    }
+}
+
+impl From<LevelKind> for LevelFlags {
+    fn from(kind: LevelKind) -> Self {
+        LevelFlags::from_bits_truncate(LevelFlags::ROOT.bits() << kind.index())
+    }
+}
+
+#[allow(unused)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_level_kind() {
+        for flags in LevelFlags::all().iter() {
+            let kind = LevelKind::try_from(flags).unwrap();
+            assert_eq!(flags, LevelFlags::from(kind));
+            assert_eq!(flags.bits().trailing_zeros() as usize - 8, kind.index());
+        }
+        assert_eq!(8, LevelFlags::all().iter().count());
+
+        assert!(LevelKind::try_from(LevelFlags::empty()).is_err());
+        assert!(LevelKind::try_from(LevelFlags::ROOT | LevelFlags::LEVEL_1).is_err());
+    }
+
+    #[test]
+    fn test_level_flags_fit_register_hi() {
+        use super::CONTENT_HASH_MASK;
+        use super::LEVEL_FLAGS_MASK;
+
+        for flags in LevelFlags::all().iter() {
+            assert_eq!(flags.bits(), flags.bits() & LEVEL_FLAGS_MASK);
+            assert_eq!(0, flags.bits() & CONTENT_HASH_MASK);
+        }
+
+        // A level after LEVEL_7 would not fit in register_hi
+        assert!((LevelFlags::LEVEL_7.bits() as u32) << 1 > u16::MAX as u32);
+
+        // Replacing the level flags preserves the content hash portion of the register
+        let handle = InternHandle::from_parts(0, LevelFlags::LEVEL_2.bits() | 0xab, 0, 0);
+        let moved = handle.with_level(LevelFlags::LEVEL_5);
+        assert_eq!(LevelFlags::LEVEL_5, moved.level_flags());
+        assert_eq!(0xab, moved.register_hi & CONTENT_HASH_MASK);
+    }
+
+    #[test]
+    fn test_compact_handle() {
+        // Compact handles halve the size of each table key
+        assert_eq!(16, std::mem::size_of::<InternHandle>());
+        assert_eq!(8, std::mem::size_of::<CompactHandle>());
+
+        let handle = InternHandle::from_parts(0x0212_3456, 0x0200 | 0x12, 0x3456, 0);
+        let compact = CompactHandle::from_handle_with_entropy(handle, 0).unwrap();
+        assert_eq!(handle.as_u64(), compact.as_u64());
+        assert_eq!(handle, compact.into_handle_with_entropy(0));
+
+        // The entropy a handle was created w/ is removed when compacted and re-applied when expanded
+        let handle = InternHandle::from_parts(0x0212_3456, 0x0200 | 0x12, 0x3456, 0x2503);
+        let compact = CompactHandle::from_handle_with_entropy(handle, 0x2503).unwrap();
+        assert_eq!(handle.as_u64(), compact.as_u64());
+        assert_eq!(handle, compact.into_handle_with_entropy(0x2503));
+
+        let entity = InternHandle::from_parts(0x0212_3456, 0x0200 | 0x12, 0x3456, 7 ^ 0x2503);
+        assert_eq!(
+            Err(InvalidCompactHandle(InternHandle::from_parts(
+                0x0212_3456,
+                0x0200 | 0x12,
+                0x3456,
+                7
+            ))),
+            CompactHandle::from_handle_with_entropy(entity, 0x2503)
+        );
+    }
+}
@@ -1,23 +1,35 @@
 use std::any::TypeId;
+use std::cell::Cell;
+use std::collections::btree_map::Entry;
 use std::collections::BTreeMap;
+use std::fmt::Display;
 use std::hash::Hash;
 use std::ops::Deref;
 use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::OnceLock;
 use std::sync::Weak;
 
 use anyhow::anyhow;
-use serde::Deserialize;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 use tracing::trace;
-use tracing::warn;
 
+use crate::define_intern_table;
 use crate::entity::ENTITY;
 use crate::entropy::ENTROPY;
 use crate::prelude::Repr;
 use crate::repr::node::SourceSpan;
 
+pub use crate::handle_core::CompactHandle;
+pub use crate::handle_core::InternHandle;
+pub use crate::handle_core::InvalidCompactHandle;
+pub use crate::handle_core::InvalidLevelFlags;
+pub use crate::handle_core::LevelFlags;
+pub use crate::handle_core::LevelKind;
+
 pub type InternResult = anyhow::Result<InternHandle>;
 
 /// This trait is based on the concept of string interning where the
@@ -36,6 +48,33 @@ pub trait InternerFactory {
         assign: impl Fn(InternHandle) -> anyhow::Result<()> + Send + Sync + 'static,
     );
 
+    /// Pushes a tag w/ a precomputed hash of the tag value to the current interner state,
+    ///
+    /// **Note** Allows large values (i.e. node sources) to be hashed once w/ a faster hasher, so that the interner
+    /// only needs to hash the precomputed value. By default the precomputed value is pushed in place of the tag value.
+    ///
+    fn push_tag_prehashed(
+        &mut self,
+        precomputed: u64,
+        assign: impl Fn(InternHandle) -> anyhow::Result<()> + Send + Sync + 'static,
+    ) {
+        self.push_tag(precomputed, assign)
+    }
+
+    /// Pushes an assign thunk w/o a tag value to the current interner state,
+    ///
+    /// **Note** The assigned value is not part of the identity of the intern handle, so levels that only differ by the
+    /// assigned value share a handle. Values should be assigned w/ `InternTable::assign_unique`, so that assigning a
+    /// different value to a shared handle returns an error. By default a unit value is pushed as the tag value, which
+    /// does not write to the hasher of the interner.
+    ///
+    fn push_assign(
+        &mut self,
+        assign: impl Fn(InternHandle) -> anyhow::Result<()> + Send + Sync + 'static,
+    ) {
+        self.push_tag((), assign)
+    }
+
     /// Sets the current level flags for the interner,
     ///
     /// **Note**: The flag should be cleared when interner is called
@@ -51,28 +90,136 @@ pub trait InternerFactory {
     /// Finishes generating the current intern handle and consumes the current stack of tags,
     ///
     fn interner(&mut self) -> InternResult;
-}
 
-/// Handle which can be converted into a 64-bit key,
-///
-#[derive(
-    Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
-)]
-pub struct InternHandle {
-    /// Link value,
+    /// Returns the name of the family of interners this interner belongs to,
     ///
-    pub(crate) link: u32,
-    /// Upper register,
+    /// When a level is configured by a linker, the family of the interner is recorded for the level's handle in the
+    /// `INTERNER_FAMILY` table. Handles recorded w/ different families cannot be linked together, since the data
+    /// value of each family has different semantics (i.e. an entity id vs. entropy).
     ///
-    /// **Note on CrcInterner impl**: The first half of the upper register contains the level bits.
+    /// **Note** By default the family is the type name of the interner. Wrapper interners that do not change how
+    /// handles are created should return the family of the inner interner.
     ///
-    pub(crate) register_hi: u16,
-    /// Lower register,
+    fn family(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+}
+
+// Intern table for the family of the interner that configured a level handle
+define_intern_table!(INTERNER_FAMILY: &'static str);
+
+thread_local!(
+    /// True while the current thread is computing intern handles w/o assigning any values, see `DryRunGuard`,
     ///
-    pub(crate) register_lo: u16,
-    /// Data register,
+    static DRY_RUN: Cell<bool> = const { Cell::new(false) }
+);
+
+/// Guard that disables assigning values to intern tables on the current thread until dropped,
+///
+/// **Note** Used by `Linker::dry_run` so that configuring a level only computes the level's handle. This includes the
+/// values assigned by an interner itself, i.e. the entity id assigned by `EntityInterner`.
+///
+pub(crate) struct DryRunGuard {
+    /// Dry run state to restore,
+    ///
+    previous: bool,
+}
+
+impl DryRunGuard {
+    /// Starts a dry run on the current thread, returning a guard that restores the previous state,
     ///
-    pub(crate) data: u64,
+    pub(crate) fn new() -> Self {
+        Self {
+            previous: DRY_RUN.replace(true),
+        }
+    }
+}
+
+impl Drop for DryRunGuard {
+    fn drop(&mut self) {
+        DRY_RUN.set(self.previous);
+    }
+}
+
+/// Type-alias for an observer of intern handles created by an interner,
+///
+#[cfg(feature = "observe")]
+type HandleObserver = Box<dyn Fn(InternHandle) + Send + Sync + 'static>;
+
+/// Observers registered w/ `on_handle_created`,
+///
+#[cfg(feature = "observe")]
+static HANDLE_OBSERVERS: std::sync::RwLock<Vec<HandleObserver>> = std::sync::RwLock::new(vec![]);
+
+/// Registers a global observer that is called w/ every intern handle created by an interner,
+///
+/// **Note** Observers are called at the end of `CrcInterner::interner`, interners that wrap a crc interner (i.e.
+/// `EntityInterner`) return the handle created by the inner interner so the handle is only observed once. Observers
+/// cannot be removed, and should not register other observers.
+///
+/// **Note** Handles computed during a dry run are not observed, since no values are assigned to them, see
+/// `Linker::dry_run`.
+///
+#[cfg(feature = "observe")]
+pub fn on_handle_created(f: impl Fn(InternHandle) + Send + Sync + 'static) {
+    if let Ok(mut observers) = HANDLE_OBSERVERS.write() {
+        observers.push(Box::new(f));
+    }
+}
+
+/// Calls each registered observer w/ a created intern handle,
+///
+#[cfg(feature = "observe")]
+pub(crate) fn notify_handle_created(handle: InternHandle) {
+    if DRY_RUN.get() {
+        return;
+    }
+
+    if let Ok(observers) = HANDLE_OBSERVERS.read() {
+        for observer in observers.iter() {
+            observer(handle);
+        }
+    }
+}
+
+impl<I: InternerFactory> InternerFactory for &mut I {
+    #[inline]
+    fn push_tag<T: Hash + Send + Sync + 'static>(
+        &mut self,
+        value: T,
+        assign: impl Fn(InternHandle) -> anyhow::Result<()> + Send + Sync + 'static,
+    ) {
+        (**self).push_tag(value, assign)
+    }
+
+    #[inline]
+    fn push_tag_prehashed(
+        &mut self,
+        precomputed: u64,
+        assign: impl Fn(InternHandle) -> anyhow::Result<()> + Send + Sync + 'static,
+    ) {
+        (**self).push_tag_prehashed(precomputed, assign)
+    }
+
+    #[inline]
+    fn set_level_flags(&mut self, flags: LevelFlags) {
+        (**self).set_level_flags(flags)
+    }
+
+    #[inline]
+    fn set_data(&mut self, data: u64) {
+        (**self).set_data(data)
+    }
+
+    #[inline]
+    fn interner(&mut self) -> InternResult {
+        (**self).interner()
+    }
+
+    #[inline]
+    fn family(&self) -> &'static str {
+        (**self).family()
+    }
 }
 
 impl From<u64> for InternHandle {
@@ -90,90 +237,94 @@ impl From<u64> for InternHandle {
     }
 }
 
-impl InternHandle {
-    /// Returns the current data value,
-    ///
-    pub fn data(&self) -> u64 {
-        self.data ^ ENTROPY.get()
-    }
+/// Alphabet used to display intern handles (RFC 4648 base32, lowercase),
+///
+const DISPLAY_ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
 
-    /// Returns the current entity id of the intern handle,
+impl Display for InternHandle {
+    /// Writes the unpadded base32 encoding of `as_u64()`,
     ///
-    pub fn entity(&self) -> Option<u64> {
-        let data = self.data();
+    /// **Note** The data value is not included, so the token is independent of entropy.
+    ///
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = self.as_u64();
 
-        ENTITY.copy(self).filter(|v| *v == data).map(|_| data)
+        // 64 bits are written as 13 5-bit groups, the first group holds the upper 4 bits
+        for group in (0..13).rev() {
+            let idx = (value >> (group * 5)) & 0x1f;
+            write!(f, "{}", DISPLAY_ALPHABET[idx as usize] as char)?;
+        }
+
+        Ok(())
     }
+}
 
-    /// Returns the current level flag enabled for this intern handle,
+impl InternHandle {
+    /// Returns the current data value,
     ///
-    #[inline]
-    pub fn level_flags(&self) -> LevelFlags {
-        LevelFlags::from_bits_truncate(self.register_hi)
+    pub fn data(&self) -> u64 {
+        self.data ^ ENTROPY.get()
     }
 
-    /// Converts the handle to a u64 value,
+    /// Returns a copy of this handle w/ the current entropy removed from the data value,
     ///
-    /// **Note**: This contains the full handle value.
+    /// **Note**: A canonical handle can be shared w/ a process that has a different entropy value,
+    /// and converted back into a local handle w/ `with_entropy`.
     ///
     #[inline]
-    pub fn as_u64(&self) -> u64 {
-        self.as_uuid().as_u64_pair().0
+    pub fn canonical(&self) -> InternHandle {
+        let mut handle = *self;
+        handle.data = self.data();
+        handle
     }
 
-    /// Returns as a uuid,
+    /// Returns a copy of a canonical handle w/ the current entropy applied to the data value,
     ///
     #[inline]
-    pub fn as_uuid(&self) -> uuid::Uuid {
-        uuid::Uuid::from_fields(self.link, self.register_hi, self.register_lo, &[0; 8])
+    pub fn with_entropy(&self) -> InternHandle {
+        let mut handle = *self;
+        handle.data = self.data ^ ENTROPY.get();
+        handle
     }
 
-    /// Returns the register value of the current handle,
+    /// Returns the current entity id of the intern handle,
     ///
-    #[inline]
-    pub fn register(&self) -> u32 {
-        bytemuck::cast::<[u16; 2], u32>([self.register_lo, self.register_hi])
+    /// **Note** Entity ids are assigned to level handles, so the link value is ignored.
+    ///
+    pub fn entity(&self) -> Option<u64> {
+        let data = self.data();
+        let (_, current) = self.node();
+
+        ENTITY.copy(&current).filter(|v| *v == data).map(|_| data)
     }
 
-    /// Returns true if the current handle is a root handle,
+    /// Returns true if the level of this handle has been linked,
+    ///
+    /// Every linked level handle is assigned in the `HANDLES` table, so this is the cheapest check for a dangling
+    /// handle, i.e. a handle that was fabricated, or whose level has been removed.
+    ///
+    /// **Note** The level is resolved w/ the entropy of the current thread.
     ///
     #[inline]
-    pub fn is_root(&self) -> bool {
-        self.level_flags() == LevelFlags::ROOT
+    pub fn is_registered(&self) -> bool {
+        let (_, level) = self.node();
+        crate::repr::HANDLES.contains(&level)
     }
 
-    /// Returns true if the current handle is a node handle,
-    ///
-    /// **Note** A node handle contains a non-zero link value.
+    /// Returns as a uuid,
     ///
     #[inline]
-    pub fn is_node(&self) -> bool {
-        self.link > 0
+    pub fn as_uuid(&self) -> uuid::Uuid {
+        uuid::Uuid::from_fields(self.link, self.register_hi, self.register_lo, &[0; 8])
     }
 
     /// Returns a split view of the current intern handle providing the current and previous nodes,
     ///
+    /// **Note** The previous node is reconstructed w/ the entropy of the current thread.
+    ///
+    #[inline]
     pub fn node(&self) -> (Option<InternHandle>, InternHandle) {
-        let prev = self.link ^ self.register();
-
-        let [lo, hi] = bytemuck::cast::<u32, [u16; 2]>(prev);
-
-        let prev_level = LevelFlags::from_bits_truncate(hi);
-
-        let mut prev_handle = None;
-        if prev_level.bits() << 1 == self.level_flags().bits() {
-            let _ = prev_handle.insert(InternHandle {
-                link: 0,
-                register_hi: hi,
-                register_lo: lo,
-                data: ENTROPY.get(),
-            });
-        }
-
-        let mut current = *self;
-        current.link = 0;
-
-        (prev_handle, current)
+        self.node_with_entropy(ENTROPY.get())
     }
 
     /// Returns the resource type id,
@@ -197,6 +348,13 @@ impl InternHandle {
         crate::repr::resource::TYPE_SIZE.copy(self)
     }
 
+    /// Returns the trait name of a dyn resource,
+    ///
+    #[inline]
+    pub fn resource_trait_name(&self) -> Option<&'static str> {
+        crate::repr::resource::TRAIT_NAME.copy(self)
+    }
+
     /// Returns the resource parse type name,
     ///
     #[inline]
@@ -204,6 +362,13 @@ impl InternHandle {
         crate::repr::resource::PARSE_TYPE_NAME.copy(self)
     }
 
+    /// Returns the resource parse type id,
+    ///
+    #[inline]
+    pub fn resource_parse_type_id(&self) -> Option<TypeId> {
+        crate::repr::resource::PARSE_TYPE_ID.copy(self)
+    }
+
     /// Returns the resource ffi type name,
     ///
     #[inline]
@@ -237,6 +402,20 @@ impl InternHandle {
         crate::repr::dependency::DEPENDENCY_NAME.strong_ref(self)
     }
 
+    /// Returns the name of the variant,
+    ///
+    #[inline]
+    pub fn variant_name(&self) -> Option<Arc<String>> {
+        crate::repr::variant::VARIANT_NAME.strong_ref(self)
+    }
+
+    /// Returns the discriminant of the variant,
+    ///
+    #[inline]
+    pub fn variant_discriminant(&self) -> Option<u64> {
+        crate::repr::variant::VARIANT_DISCRIMINANT.copy(self)
+    }
+
     /// Returns the name of the receiver,
     ///
     #[inline]
@@ -356,6 +535,20 @@ impl InternHandle {
         crate::repr::node::SOURCE_RELATIVE.strong_ref(self)
     }
 
+    /// Returns the node timestamp,
+    ///
+    #[inline]
+    pub fn timestamp(&self) -> Option<u64> {
+        crate::repr::node::TIMESTAMP.copy(self)
+    }
+
+    /// Returns the node binary payload,
+    ///
+    #[inline]
+    pub fn payload(&self) -> Option<bytes::Bytes> {
+        crate::repr::node::PAYLOAD.clone(self)
+    }
+
     /// Returns the host address,
     ///
     #[inline]
@@ -371,96 +564,334 @@ impl InternHandle {
     }
 }
 
+/// Policy for assigning a value to an intern handle that has already been assigned,
+///
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplacePolicy {
+    /// Keeps the existing value and skips the assignment,
+    ///
+    #[default]
+    Skip,
+    /// Keeps the existing value and returns an error,
+    ///
+    Error,
+}
+
+/// Trait for the type of key an intern table stores each interned value under,
+///
+pub trait InternKey: Copy + Ord + Send + Sync + 'static {
+    /// Returns the key of an intern handle, or None if the handle cannot be represented by this key,
+    ///
+    fn from_handle(handle: &InternHandle) -> Option<Self>;
+
+    /// Returns the intern handle of this key,
+    ///
+    fn into_handle(self) -> InternHandle;
+}
+
+impl InternKey for InternHandle {
+    #[inline]
+    fn from_handle(handle: &InternHandle) -> Option<Self> {
+        Some(*handle)
+    }
+
+    #[inline]
+    fn into_handle(self) -> InternHandle {
+        self
+    }
+}
+
+impl TryFrom<InternHandle> for CompactHandle {
+    type Error = InvalidCompactHandle;
+
+    /// Compacts the canonical handle, i.e. the handle w/ the entropy of the current thread removed,
+    ///
+    fn try_from(handle: InternHandle) -> Result<Self, Self::Error> {
+        CompactHandle::from_handle_with_entropy(handle, ENTROPY.get())
+    }
+}
+
+impl From<CompactHandle> for InternHandle {
+    /// Expands the compact handle w/ the entropy of the current thread applied to the data value,
+    ///
+    fn from(handle: CompactHandle) -> Self {
+        handle.into_handle_with_entropy(ENTROPY.get())
+    }
+}
+
+/// Compact handles halve the size of each key, but can only key handles w/ a canonical data value of 0,
+///
+/// **Note** Entity handles cannot use the compact key, since the entity data is what distinguishes entities w/ the
+/// same content. Assigning an entity handle to a table keyed on `CompactHandle` returns an error.
+///
+#[cfg(feature = "compact-handles")]
+impl InternKey for CompactHandle {
+    #[inline]
+    fn from_handle(handle: &InternHandle) -> Option<Self> {
+        CompactHandle::try_from(*handle).ok()
+    }
+
+    #[inline]
+    fn into_handle(self) -> InternHandle {
+        self.into()
+    }
+}
+
 /// Inner intern table map,
-/// 
-pub struct InternMap<T> {
-    pub(crate) map: BTreeMap<InternHandle, Arc<T>>
+///
+pub struct InternMap<T, K = InternHandle> {
+    pub(crate) map: BTreeMap<K, Arc<T>>,
+    /// Policy to apply when assigning an existing handle,
+    ///
+    pub(crate) policy: ReplacePolicy,
 }
 
-impl<T> InternMap<T> {
+impl<T, K: InternKey> InternMap<T, K> {
     /// Returns an iterator for exporting this map,
-    /// 
-    pub fn iter_for_export(&self) -> impl Iterator<Item = (uuid::Uuid, bytes::Bytes)> + '_ 
+    ///
+    #[allow(dead_code)] // TODO (Phase1): Used when initializing intern tables from the filesystem
+    pub fn iter_for_export(&self) -> impl Iterator<Item = (uuid::Uuid, bytes::Bytes)> + '_
     where
-        T: Serialize
+        T: Serialize,
     {
         self.iter_entries().filter_map(|(k, i)| {
             i.upgrade().and_then(|i| {
-                bincode::serialize(i.deref()).ok().map(|s| {
-                    (k.as_uuid(), bytes::Bytes::copy_from_slice(s.as_ref()))
-                })
+                bincode::serialize(i.deref())
+                    .ok()
+                    .map(|s| (k.as_uuid(), bytes::Bytes::copy_from_slice(s.as_ref())))
+            })
+        })
+    }
+
+    /// Returns an iterator for exporting this map w/ the local entropy removed from each handle,
+    ///
+    /// **Note**: Unlike `iter_for_export`, the data value of each handle is preserved which allows
+    /// entity handles to be reconstructed by a process w/ a different entropy value.
+    ///
+    pub fn iter_for_export_canonical(
+        &self,
+    ) -> impl Iterator<Item = (InternHandle, bytes::Bytes)> + '_
+    where
+        T: Serialize,
+    {
+        self.iter_entries().filter_map(|(k, i)| {
+            i.upgrade().and_then(|i| {
+                bincode::serialize(i.deref())
+                    .ok()
+                    .map(|s| (k.canonical(), bytes::Bytes::copy_from_slice(s.as_ref())))
             })
         })
     }
 
     /// Returns an iterator over inner entries,
-    /// 
+    ///
     /// **Note**: Does not create a strong reference to entry, instead creates a weak reference.
-    /// 
+    ///
     pub fn iter_entries(&self) -> impl Iterator<Item = (InternHandle, Weak<T>)> + '_ {
-        self.map.iter().map(|(h, e)| {
-            (*h, Arc::downgrade(e))
-        })
+        self.map
+            .iter()
+            .map(|(k, e)| (k.into_handle(), Arc::downgrade(e)))
     }
 
     /// Prune any entries that do not have strong references,
-    /// 
-    fn _prune(&mut self) {
-
-    }
+    ///
+    fn _prune(&mut self) {}
 }
 
-impl<T> Default for InternMap<T> {
+impl<T, K> Default for InternMap<T, K> {
     fn default() -> Self {
-        Self { map: Default::default() }
+        Self {
+            map: Default::default(),
+            policy: ReplacePolicy::default(),
+        }
     }
 }
 
 /// Type-alias for inner table container,
 /// 
-type InnerTable<T> = tokio::sync::watch::Sender<InternMap<T>>;
+type InnerTable<T, K> = tokio::sync::watch::Sender<InternMap<T, K>>;
 
 /// Struct maintaining an inner shared intern table,
 ///
-pub struct InternTable<T: Send + Sync + 'static> {
+/// **Note** Values are keyed on the full intern handle by default. Tables that never use entity data can be keyed on
+/// `CompactHandle` w/ the `compact-handles` feature, i.e. `InternTable<T, CompactHandle>`.
+///
+pub struct InternTable<T: Send + Sync + 'static, K: InternKey = InternHandle> {
+    /// Name of the table,
+    ///
+    name: &'static str,
     /// Inner table,
     ///
-    inner: OnceLock<InnerTable<T>>,
+    inner: OnceLock<InnerTable<T, K>>,
+    /// True if this table has been sealed,
+    ///
+    sealed: AtomicBool,
 }
 
-impl<T: Send + Sync + 'static> InternTable<T> {
+impl<T: Send + Sync + 'static, K: InternKey> InternTable<T, K> {
     /// Creates a new empty intern table,
     ///
     #[inline]
     pub const fn new() -> Self {
+        Self::named("")
+    }
+
+    /// Creates a new empty intern table w/ a name,
+    ///
+    #[inline]
+    pub const fn named(name: &'static str) -> Self {
         Self {
+            name,
             inner: OnceLock::new(),
+            sealed: AtomicBool::new(false),
         }
     }
 
+    /// Returns the name of this table,
+    ///
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Seals this table so that any subsequent calls to `assign_intern` w/ a new handle return an error,
+    ///
+    /// **Note** Values that have already been interned can still be read and removed, and re-assigning a handle that
+    /// has already been interned is still handled by the replace policy of this table, so reprs that were linked before
+    /// the table was sealed can still be rebuilt.
+    ///
+    pub fn seal(&self) {
+        self.sealed.store(true, Ordering::Release);
+    }
+
+    /// Unseals this table so that values can be interned again,
+    ///
+    pub fn unseal(&self) {
+        self.sealed.store(false, Ordering::Release);
+    }
+
+    /// Returns true if this table has been sealed,
+    ///
+    #[inline]
+    pub fn is_sealed(&self) -> bool {
+        self.sealed.load(Ordering::Acquire)
+    }
+
+    /// Sets the policy to apply when assigning a handle that has already been assigned,
+    ///
+    pub fn set_replace_policy(&self, policy: ReplacePolicy) {
+        self.inner().send_modify(|t| t.policy = policy);
+    }
+
     /// Assigns an intern handle for an immutable value,
     ///
-    /// **Note** If the intern handle already has been assigned a value this will result in a no-op.
+    /// **Note** If the intern handle already has been assigned a value this will result in a no-op,
+    /// unless the table's replace policy is `ReplacePolicy::Error`.
+    ///
+    /// **Note** The check and insert happen under the same write lock, so concurrent assignments to
+    /// the same handle will never replace a value that has already been handed out.
+    ///
+    /// **Error** Returns an error if the handle cannot be keyed by this table, i.e. an entity handle in a table keyed on
+    /// `CompactHandle`.
+    ///
+    /// **Error** Returns an error if the handle has not been assigned and this table has been sealed, see
+    /// `InternTable::seal`.
+    ///
+    /// **Note** Assigning is a no-op while a dry run is in progress on the current thread, see `Linker::dry_run`.
     ///
     pub fn assign_intern(&self, handle: InternHandle, value: T) -> anyhow::Result<()> {
+        if DRY_RUN.get() {
+            return Ok(());
+        }
+
+        let Some(key) = K::from_handle(&handle) else {
+            return Err(anyhow!("Cannot key {:?} in table {}", handle, self.name));
+        };
+
         // Skip if the value has already been created
         {
-            if self.inner().borrow().map.contains_key(&handle) {
-                trace!("Skipping interning {:?}", handle);
-                return Ok(());
+            let table = self.inner().borrow();
+            if table.map.contains_key(&key) {
+                return Self::handle_existing(table.policy, handle);
             }
         }
-        self.inner().send_modify(|t| {
-            if t.map.insert(handle, Arc::new(value)).is_some() {
-                warn!(
-                    "Replacing intern handle {:?} {:x?}",
-                    handle.level_flags(),
-                    handle
-                );
-            }
-        });
 
-        Ok(())
+        let mut result = Ok(());
+        self.inner()
+            .send_if_modified(|t| match t.map.entry(key) {
+                Entry::Vacant(_) if self.is_sealed() => {
+                    result = Err(anyhow!("Cannot intern {:?}, table {} is sealed", handle, self.name));
+                    false
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(Arc::new(value));
+                    true
+                }
+                Entry::Occupied(_) => {
+                    result = Self::handle_existing(t.policy, handle);
+                    false
+                }
+            });
+
+        result
+    }
+
+    /// Assigns an intern handle for a value that is not part of the identity of the handle, see `push_assign`,
+    ///
+    /// **Note** Assigning the value the handle has already been assigned is a no-op.
+    ///
+    /// **Error** Returns an error if the handle has already been assigned a different value, or for the same reasons as
+    /// `assign_intern`.
+    ///
+    pub fn assign_unique(&self, handle: InternHandle, value: T) -> anyhow::Result<()>
+    where
+        T: PartialEq + std::fmt::Debug,
+    {
+        if DRY_RUN.get() {
+            return Ok(());
+        }
+
+        let Some(key) = K::from_handle(&handle) else {
+            return Err(anyhow!("Cannot key {:?} in table {}", handle, self.name));
+        };
+
+        let mut result = Ok(());
+        self.inner()
+            .send_if_modified(|t| match t.map.entry(key) {
+                Entry::Vacant(_) if self.is_sealed() => {
+                    result = Err(anyhow!("Cannot intern {:?}, table {} is sealed", handle, self.name));
+                    false
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(Arc::new(value));
+                    true
+                }
+                Entry::Occupied(entry) if **entry.get() != value => {
+                    result = Err(anyhow!(
+                        "Cannot assign {:?} to {:?} in table {}, already assigned {:?}",
+                        value,
+                        handle,
+                        self.name,
+                        entry.get()
+                    ));
+                    false
+                }
+                Entry::Occupied(_) => false,
+            });
+
+        result
+    }
+
+    /// Applies the replace policy when a handle has already been assigned,
+    ///
+    fn handle_existing(policy: ReplacePolicy, handle: InternHandle) -> anyhow::Result<()> {
+        match policy {
+            ReplacePolicy::Skip => {
+                trace!("Skipping interning {:?}", handle);
+                Ok(())
+            }
+            ReplacePolicy::Error => Err(anyhow!("Already interned {:?}", handle)),
+        }
     }
 
     /// Returns a handle to the interned value,
@@ -469,13 +900,109 @@ impl<T: Send + Sync + 'static> InternTable<T> {
     /// inner table lock is poisoned.
     ///
     pub fn get(&self, handle: &InternHandle) -> anyhow::Result<Weak<T>> {
-        if let Some(value) = self.inner().borrow().map.get(handle) {
+        let table = self.inner().borrow();
+        if let Some(value) = K::from_handle(handle).and_then(|k| table.map.get(&k)) {
             Ok(Arc::downgrade(value))
         } else {
             Err(anyhow!("Not interned {:?}", handle))
         }
     }
 
+    /// Returns true if a value has been interned for the handle,
+    ///
+    pub fn contains(&self, handle: &InternHandle) -> bool {
+        K::from_handle(handle).is_some_and(|k| self.inner().borrow().map.contains_key(&k))
+    }
+
+    /// Removes the value assigned to handle, returning the removed value,
+    ///
+    /// **Note** Strong references to the value that have already been handed out remain valid.
+    ///
+    pub fn remove(&self, handle: &InternHandle) -> Option<Arc<T>> {
+        let key = K::from_handle(handle)?;
+
+        let mut removed = None;
+        self.inner().send_if_modified(|t| {
+            removed = t.map.remove(&key);
+            removed.is_some()
+        });
+        removed
+    }
+
+    /// Returns the number of entries in the table,
+    ///
+    pub fn len(&self) -> usize {
+        self.inner().borrow().map.len()
+    }
+
+    /// Returns true if the table has no entries,
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the approximate memory used by the entries of the table in bytes,
+    ///
+    /// **Note** Only the size of each key, value and reference count is counted, memory allocated by a value (i.e. the
+    /// contents of a `String`) and the overhead of the map are not.
+    ///
+    pub fn approx_memory(&self) -> usize {
+        let entry = std::mem::size_of::<K>()
+            + std::mem::size_of::<Arc<T>>()
+            + std::mem::size_of::<T>()
+            + 2 * std::mem::size_of::<usize>();
+
+        self.len() * entry
+    }
+
+    /// Returns a strong reference to the value of each handle w/ a single borrow of the table,
+    ///
+    /// **Note** The returned vector is in the same order as handles.
+    ///
+    pub fn get_many(&self, handles: &[InternHandle]) -> Vec<Option<Arc<T>>> {
+        let table = self.inner().borrow();
+        handles
+            .iter()
+            .map(|h| K::from_handle(h).and_then(|k| table.map.get(&k)).cloned())
+            .collect()
+    }
+
+    /// Returns all entries currently in the table,
+    ///
+    pub fn entries(&self) -> Vec<(InternHandle, Arc<T>)> {
+        self.inner()
+            .borrow()
+            .map
+            .iter()
+            .map(|(k, v)| (k.into_handle(), v.clone()))
+            .collect()
+    }
+
+    /// Exports all entries w/ the local entropy removed from each handle,
+    ///
+    pub fn export_canonical(&self) -> Vec<(InternHandle, bytes::Bytes)>
+    where
+        T: Serialize,
+    {
+        self.inner().borrow().iter_for_export_canonical().collect()
+    }
+
+    /// Imports entries exported by `export_canonical` and applies the local entropy to each handle,
+    ///
+    pub fn import_canonical(
+        &self,
+        entries: impl IntoIterator<Item = (InternHandle, bytes::Bytes)>,
+    ) -> anyhow::Result<()>
+    where
+        T: DeserializeOwned,
+    {
+        for (handle, value) in entries {
+            let value = bincode::deserialize(value.as_ref())?;
+            self.assign_intern(handle.with_entropy(), value)?;
+        }
+        Ok(())
+    }
+
     /// Returns a copy of the interned value from a handle,
     ///
     pub fn copy(&self, handle: &InternHandle) -> Option<T>
@@ -516,9 +1043,9 @@ impl<T: Send + Sync + 'static> InternTable<T> {
 
     /// Returns a reference to the inner table,
     /// 
-    fn inner(&self) -> &InnerTable<T> {
+    fn inner(&self) -> &InnerTable<T, K> {
         self.inner.get_or_init(|| {
-            let (tx, _) = tokio::sync::watch::channel(InternMap::<T>::default());
+            let (tx, _) = tokio::sync::watch::channel(InternMap::<T, K>::default());
 
             tx
         })
@@ -526,52 +1053,139 @@ impl<T: Send + Sync + 'static> InternTable<T> {
 
     /// Returns a file name to use for the table,
     /// 
+    #[allow(dead_code)] // TODO (Phase1): Used when initializing intern tables from the filesystem
     fn table_file_name(&self) {
 
     }
 }
 
-impl<T: Send + Sync + 'static> Default for InternTable<T> {
+impl<T: Send + Sync + 'static, K: InternKey> Default for InternTable<T, K> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-bitflags::bitflags! {
-    /// Representation level flags,
-    ///
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-    pub struct LevelFlags : u16 {
-        /// Root representation level
-        ///
-        const ROOT = 0x0100;
-
-        /// Representation Level 1
-        ///
-        const LEVEL_1 = 0x0100 << 1;
+#[allow(unused)]
+mod tests {
+    use crate::entropy::ENTROPY;
+    use crate::prelude::*;
+
+    use crate::macros::test_field;
+
+    struct Test;
+
+    test_field!(Test, 0, "interner");
+
+    #[test]
+    #[cfg(feature = "compact-handles")]
+    fn test_compact_intern_table() {
+        static COMPACT: InternTable<String, CompactHandle> = InternTable::named("COMPACT");
+
+        let handle = InternHandle::from_parts(0x0250_3000, 0x0100 | 0x25, 0x0300, 0);
+        COMPACT
+            .assign_intern(handle, String::from("compact"))
+            .unwrap();
+        assert!(COMPACT.contains(&handle));
+        assert_eq!(Some(String::from("compact")), COMPACT.clone(&handle));
+        assert_eq!(
+            vec![handle],
+            COMPACT
+                .entries()
+                .into_iter()
+                .map(|(h, _)| h)
+                .collect::<Vec<_>>()
+        );
+
+        // Entity handles cannot be keyed on a compact handle
+        let entity = InternHandle::from_parts(0x0250_3000, 0x0100 | 0x25, 0x0300, 1);
+        assert!(COMPACT
+            .assign_intern(entity, String::from("entity"))
+            .is_err());
+        assert!(!COMPACT.contains(&entity));
+    }
 
-        /// Representation Level 2
-        ///
-        const LEVEL_2 = 0x0100 << 2;
+    #[test]
+    fn test_compact_handle_new_runtime() {
+        struct Compact;
+
+        // Each worker of the runtime shares the runtime's entropy, so keep it off of the test thread
+        std::thread::spawn(|| {
+            let runtime = new_runtime().build().unwrap();
+            runtime
+                .block_on(runtime.spawn(async {
+                    let repr = Linker::new_crc::<Compact>().link().unwrap();
+                    assert_ne!(0, repr.tail.data);
+                    assert_eq!(0, repr.tail.data());
+
+                    let compact = CompactHandle::try_from(repr.tail).unwrap();
+                    assert_eq!(repr.tail, InternHandle::from(compact));
+
+                    #[cfg(feature = "compact-handles")]
+                    {
+                        static COMPACT_RUNTIME: InternTable<String, CompactHandle> =
+                            InternTable::named("COMPACT_RUNTIME");
+
+                        COMPACT_RUNTIME
+                            .assign_intern(repr.tail, String::from("runtime"))
+                            .unwrap();
+                        assert_eq!(
+                            Some(String::from("runtime")),
+                            COMPACT_RUNTIME.clone(&repr.tail)
+                        );
+                    }
+
+                    // Entity handles cannot be compacted
+                    let entity = Linker::new_entity_crc::<Compact>().link().unwrap();
+                    assert!(CompactHandle::try_from(entity.tail).is_err());
+                }))
+                .unwrap();
+        })
+        .join()
+        .unwrap();
+    }
 
-        /// Representation Level 3
-        ///
-        const LEVEL_3 = 0x0100 << 3;
+    #[test]
+    fn test_handle_core_matches_interner() {
+        let mut linker = Test::linker::<CrcInterner>().unwrap();
+        linker
+            .push_level(NodeLevel::new().with_input("handle core"))
+            .unwrap();
+        let repr = linker.link().unwrap();
+
+        for handle in repr.get_levels().into_iter().chain([
+            repr.tail,
+            InternHandle::from_parts(u32::MAX, 0x0212, 0x3456, 7),
+        ]) {
+            // as_u64 previously went through a uuid w/ the handle fields
+            let uuid = uuid::Uuid::from_fields(
+                handle.link,
+                handle.register_hi,
+                handle.register_lo,
+                &[0; 8],
+            );
+            assert_eq!(uuid.as_u64_pair().0, handle.as_u64());
+            assert_eq!(handle.as_uuid(), uuid);
+
+            assert_eq!(
+                (handle.register_hi as u32) << 16 | handle.register_lo as u32,
+                handle.register()
+            );
+            assert_eq!(handle.node(), handle.node_with_entropy(ENTROPY.get()));
+        }
 
-        /// Representation level 4
-        ///
-        const LEVEL_4 = 0x0100 << 4;
+        let (prev, current) = repr.tail.node_with_entropy(0x1234);
+        assert_eq!(0x1234, prev.unwrap().data);
+        assert_eq!(0, current.link);
+    }
 
-        /// Representation level 5
-        ///
-        const LEVEL_5 = 0x0100 << 5;
+    #[test]
+    fn test_is_registered() {
+        struct Registered;
 
-        /// Representation level 6
-        ///
-        const LEVEL_6 = 0x0100 << 6;
+        let repr = Linker::new_crc::<Registered>().link().unwrap();
+        assert!(repr.tail.is_registered());
 
-        /// Representation level 7
-        ///
-        const LEVEL_7 = 0x0100 << 7;
+        let fabricated = InternHandle::from_parts(0x2521, 0x2521, 0x2521, 0x2521);
+        assert!(!fabricated.is_registered());
     }
 }
@@ -1,4 +1,4 @@
-use crate::interner::{InternResult, InternerFactory};
+use crate::interner::{InternResult, InternerFactory, INTERNER_FAMILY};
 
 /// Trait for each level of representation that defines how
 /// each level configures the intern handle representing a resource.
@@ -16,3 +16,14 @@ pub trait Level {
     ///
     fn mount(&self) -> Self::Mount;
 }
+
+/// Configures a level w/ interner and records the family of the interner for the configured handle,
+///
+pub(crate) fn configure_level(
+    level: &impl Level,
+    interner: &mut impl InternerFactory,
+) -> InternResult {
+    let handle = level.configure(interner)?;
+    INTERNER_FAMILY.assign_intern(handle, interner.family())?;
+    Ok(handle)
+}
@@ -1,5 +1,7 @@
+mod boxed;
 mod entity;
 mod entropy;
+mod handle_core;
 mod interner;
 mod level;
 mod linker;
@@ -40,7 +42,40 @@ mod macros {
     #[macro_export]
     macro_rules! define_intern_table {
         ($table:ident: $ty:ty) => {
-            pub static $table: InternTable<$ty> = InternTable::<$ty>::new();
+            pub static $table: InternTable<$ty> = InternTable::<$ty>::named(stringify!($table));
+        };
+    }
+
+    /// Links the field repr of each `Field<N>` implementation of a type,
+    ///
+    /// Fields are linked w/ a crc interner, which matches the interner `Recv::link_recv` links the receiver w/, unless
+    /// an interner is provided w/ `as`.
+    ///
+    /// **Example**
+    ///
+    /// ```rs no_run
+    /// impl Recv for Example {
+    ///     fn symbol() -> &'static str {
+    ///         "example"
+    ///     }
+    ///
+    ///     fn fields() -> anyhow::Result<Vec<Repr>> {
+    ///         field_reprs!(Example: 0, 1, 2)
+    ///     }
+    /// }
+    ///
+    /// let fields = field_reprs!(Example as EntityInterner<CrcInterner>: 0, 1, 2)?;
+    /// ```
+    ///
+    #[macro_export]
+    macro_rules! field_reprs {
+        ($ty:ty as $interner:ty: $($offset:literal),* $(,)?) => {
+            (|| -> anyhow::Result<Vec<Repr>> {
+                Ok(vec![$(<$ty as Field<$offset>>::linker::<$interner>()?.link()?),*])
+            })()
+        };
+        ($ty:ty: $($offset:literal),* $(,)?) => {
+            $crate::field_reprs!($ty as CrcInterner: $($offset),*)
         };
     }
 
@@ -64,6 +99,32 @@ mod macros {
             $interner.push_tag($a.to_string(), move |h| tag.assign(h));
         };
     }
+
+    /// Implements `Field<OFFSET>` for a test owner type,
+    ///
+    /// **Note** The parse, projected and ffi types default to `String`, a single type is used for all three.
+    ///
+    macro_rules! test_field {
+        ($owner:ty, $offset:literal, $name:literal) => {
+            $crate::macros::test_field!($owner, $offset, $name, String);
+        };
+        ($owner:ty, $offset:literal, $name:literal, $ty:ty) => {
+            $crate::macros::test_field!($owner, $offset, $name, $ty, $ty, $ty);
+        };
+        ($owner:ty, $offset:literal, $name:literal, $parse:ty, $projected:ty, $ffi:ty) => {
+            impl Field<$offset> for $owner {
+                type ParseType = $parse;
+                type ProjectedType = $projected;
+                type FFIType = $ffi;
+
+                fn field_name() -> &'static str {
+                    $name
+                }
+            }
+        };
+    }
+
+    pub(crate) use test_field;
 }
 
 pub mod prelude {
@@ -71,13 +132,22 @@ pub mod prelude {
     pub use super::macros::*;
     pub use crate::repr::prelude::*;
 
+    pub use super::linker::IncrementalLinker;
     pub use super::linker::Linker;
 
+    #[cfg(feature = "observe")]
+    pub use super::interner::on_handle_created;
+    pub use super::interner::CompactHandle;
     pub use super::interner::InternHandle;
+    pub use super::interner::InternKey;
     pub use super::interner::InternResult;
     pub use super::interner::InternTable;
     pub use super::interner::InternerFactory;
+    pub use super::interner::InvalidCompactHandle;
+    pub use super::interner::InvalidLevelFlags;
     pub use super::interner::LevelFlags;
+    pub use super::interner::LevelKind;
+    pub use super::interner::ReplacePolicy;
 
     pub use super::tag::Tag;
 
@@ -85,9 +155,13 @@ pub mod prelude {
 
     #[cfg(feature = "crc-interner")]
     pub use super::crc::CrcInterner;
+    #[cfg(feature = "crc-interner")]
+    pub use super::crc::CrcInternerPool;
 
     pub use super::entity::EntityInterner;
 
+    pub use super::boxed::BoxedInterner;
+
     pub use super::entropy::new_runtime;
 
     /// Type-alias for a function that takes an intern handle and returns a future,
@@ -107,6 +181,29 @@ mod tests {
 
     define_intern_table!(TEST_INTERNER: &'static str);
 
+    define_intern_table!(TEST_SEALED: &'static str);
+
+    #[test]
+    fn test_seal() {
+        let handle = InternHandle::from_parts(0, 0x2517, 0x2517, 0);
+
+        TEST_SEALED.seal();
+        assert!(TEST_SEALED.is_sealed());
+        assert!(TEST_SEALED.assign_intern(handle, "sealed").is_err());
+        assert!(!TEST_SEALED.contains(&handle));
+
+        TEST_SEALED.unseal();
+        assert!(!TEST_SEALED.is_sealed());
+        TEST_SEALED.assign_intern(handle, "unsealed").unwrap();
+        assert_eq!(Some("unsealed"), TEST_SEALED.copy(&handle));
+
+        // Re-assigning an existing handle is still allowed once sealed
+        TEST_SEALED.seal();
+        TEST_SEALED.assign_intern(handle, "resealed").unwrap();
+        assert_eq!(Some("unsealed"), TEST_SEALED.copy(&handle));
+        TEST_SEALED.unseal();
+    }
+
     #[tokio::test]
     async fn test_intern_table() {
         TEST_INTERNER
@@ -186,6 +283,120 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_intern_handle_from_parts() {
+        let handle = InternHandle::from_parts(0xdead_beef, 0x0212, 0x3456, 0);
+
+        assert_eq!(0x0212_3456, handle.register());
+        assert_eq!(LevelFlags::LEVEL_1, handle.level_flags());
+        assert_eq!(0xdead_beef_0212_3456, handle.as_u64());
+        assert_eq!(handle, InternHandle::from(handle.as_u64()));
+    }
+
+    #[test]
+    fn test_intern_handle_display() {
+        let handle = InternHandle::from_parts(0xdead_beef, 0x0212, 0x3456, 0);
+        let other_data = InternHandle::from_parts(0xdead_beef, 0x0212, 0x3456, 0x1234);
+        let other_register = InternHandle::from_parts(0xdead_beef, 0x0212, 0x3457, 0);
+
+        assert_eq!(handle.as_u64(), other_data.as_u64());
+        assert_eq!(handle.to_string(), other_data.to_string());
+        assert_ne!(handle.to_string(), other_register.to_string());
+        assert_eq!(13, handle.to_string().len());
+        assert_eq!(
+            "aaaaaaaaaaaab",
+            InternHandle::from_parts(0, 0, 1, 0).to_string()
+        );
+    }
+
+    define_intern_table!(TEST_STRICT: String);
+
+    #[test]
+    fn test_tag_assign_error_context() {
+        TEST_STRICT.set_replace_policy(ReplacePolicy::Error);
+
+        let tag = Tag::new(&TEST_STRICT, Arc::new("hello world".to_string()));
+        tag.assign(InternHandle::default()).unwrap();
+
+        let err = tag.assign(InternHandle::default()).unwrap_err();
+        assert!(format!("{err:#}").contains("TEST_STRICT"));
+        assert!(format!("{err:#}").contains("Already interned"));
+    }
+
+    #[test]
+    fn test_export_canonical() {
+        struct Test;
+
+        let (entries, canonical) = std::thread::spawn(|| {
+            crate::entropy::ENTROPY.set(0x1234);
+
+            let repr = Linker::new_entity_crc::<Test>().link().unwrap();
+            let (_, handle) = repr.tail.node();
+            assert_eq!(Some(1), handle.entity());
+
+            let entries = crate::entity::ENTITY
+                .export_canonical()
+                .into_iter()
+                .filter(|(h, _)| *h == handle.canonical())
+                .collect::<Vec<_>>();
+
+            (entries, handle.canonical())
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(1, entries.len());
+        assert_eq!(1, canonical.data);
+
+        std::thread::spawn(move || {
+            crate::entropy::ENTROPY.set(0x5678);
+
+            let handle = canonical.with_entropy();
+            assert_eq!(None, handle.entity());
+
+            crate::entity::ENTITY.import_canonical(entries).unwrap();
+            assert_eq!(Some(1), handle.entity());
+        })
+        .join()
+        .unwrap();
+    }
+
+    define_intern_table!(TEST_CONCURRENT: usize);
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+    async fn test_intern_table_concurrent() {
+        let mut tasks = tokio::task::JoinSet::new();
+
+        for task in 0..64usize {
+            tasks.spawn(async move {
+                let mut weak_refs = vec![];
+                for key in 0..32u64 {
+                    // Overlapping handles across tasks, the first assignment wins
+                    let handle = InternHandle::from(key + 1);
+                    TEST_CONCURRENT.assign_intern(handle, task).unwrap();
+
+                    let value = TEST_CONCURRENT.get(&handle).unwrap();
+                    assert!(value.upgrade().is_some());
+                    weak_refs.push((handle, value));
+                    tokio::task::yield_now().await;
+                }
+                weak_refs
+            });
+        }
+
+        while let Some(weak_refs) = tasks.join_next().await {
+            for (handle, value) in weak_refs.unwrap() {
+                // Values handed out must never be replaced by a later assignment
+                let value = value.upgrade().expect("should not have been replaced");
+                assert_eq!(*value, TEST_CONCURRENT.copy(&handle).unwrap());
+            }
+        }
+
+        for key in 0..32u64 {
+            assert!(TEST_CONCURRENT.copy(&InternHandle::from(key + 1)).is_some());
+        }
+    }
+
     #[test]
     #[tracing_test::traced_test]
     fn test_intern_handle_link() {
@@ -250,6 +461,5 @@ mod tests {
 
         let mut test = Test::linker::<CrcInterner>().unwrap();
         eprintln!("{:x?}", test.link().unwrap());
-        ()
     }
 }
@@ -1,5 +1,11 @@
+use crate::boxed::HashedBytes;
+use crate::entropy::EntropyGuard;
+use crate::interner::DryRunGuard;
+use crate::level::configure_level;
 use crate::prelude::*;
 use crate::repr::HANDLES;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::sync::Arc;
 
 /// Struct for linking together levels into a single representation,
@@ -15,6 +21,155 @@ where
     /// Vector of intern handles tags for each level of the current representation,
     ///
     levels: Vec<Tag<InternHandle, Arc<InternHandle>>>,
+    /// Calls made to the interner by each level that has been pushed to this linker,
+    ///
+    pushed: Vec<RecordedLevel>,
+    /// Creates a new interner to re-configure levels w/, if not set a default interner is created,
+    ///
+    new_interner: Option<fn() -> I>,
+    /// Repr produced by the last call to `link`, keyed by a hash of the level handles it was linked from,
+    ///
+    cached: Option<(u64, Repr)>,
+}
+
+/// Object-safe trait for re-configuring a level that has been pushed to a linker,
+///
+trait PushedLevel<I>: Send + Sync {
+    /// Configures the level w/ the interner of the linker,
+    ///
+    fn configure_level(&self, interner: &mut I) -> InternResult;
+}
+
+impl<I: InternerFactory, L: Level + Send + Sync> PushedLevel<I> for L {
+    fn configure_level(&self, interner: &mut I) -> InternResult {
+        configure_level(self, interner)
+    }
+}
+
+/// Type-alias for an assign thunk that can be pushed each time a recorded level is configured,
+///
+type RecordedAssign = Arc<dyn Fn(InternHandle) -> anyhow::Result<()> + Send + Sync + 'static>;
+
+/// Call made by a level to an interner while the level was pushed,
+///
+enum RecordedCall {
+    /// Pushes a tag w/ the bytes written by the tag value's `Hash` impl,
+    ///
+    PushTag(Arc<HashedBytes>, RecordedAssign),
+    /// Pushes a tag w/ a precomputed hash of the tag value,
+    ///
+    PushTagPrehashed(u64, RecordedAssign),
+    /// Sets the current level flags,
+    ///
+    SetLevelFlags(LevelFlags),
+    /// Sets the current data value,
+    ///
+    SetData(u64),
+    /// Finishes generating the current intern handle,
+    ///
+    Interner,
+}
+
+/// Calls a level made to the interner of a linker when the level was pushed, so that the level can be re-configured,
+///
+/// **Note** Each call is replayed in the same order when the recorded level is configured. Since tag values are
+/// recorded as the bytes written by their `Hash` impl, configuring a recorded level creates the same handle as
+/// configuring the level it was recorded from. Values a level assigns to the handle returned by `interner()`, rather
+/// than w/ a tag, are only assigned when the level is pushed.
+///
+#[derive(Default)]
+struct RecordedLevel {
+    /// Calls made by the level to the interner,
+    ///
+    calls: Vec<RecordedCall>,
+}
+
+impl Level for RecordedLevel {
+    fn configure(&self, interner: &mut impl InternerFactory) -> InternResult {
+        let mut handle = Err(anyhow::anyhow!("Recorded level did not create a handle"));
+
+        for call in self.calls.iter() {
+            match call {
+                RecordedCall::PushTag(bytes, assign) => {
+                    let assign = assign.clone();
+                    interner.push_tag(bytes.clone(), move |h| assign(h));
+                }
+                RecordedCall::PushTagPrehashed(precomputed, assign) => {
+                    let assign = assign.clone();
+                    interner.push_tag_prehashed(*precomputed, move |h| assign(h));
+                }
+                RecordedCall::SetLevelFlags(flags) => interner.set_level_flags(*flags),
+                RecordedCall::SetData(data) => interner.set_data(*data),
+                RecordedCall::Interner => handle = interner.interner(),
+            }
+        }
+
+        handle
+    }
+
+    type Mount = ();
+
+    fn mount(&self) -> Self::Mount {}
+}
+
+/// Interner that records the calls made by a level to an inner interner, see `RecordedLevel`,
+///
+struct RecordingInterner<'a, I> {
+    /// Inner interner,
+    ///
+    inner: &'a mut I,
+    /// Calls recorded so far,
+    ///
+    recorded: RecordedLevel,
+}
+
+impl<I: InternerFactory> InternerFactory for RecordingInterner<'_, I> {
+    fn push_tag<T: Hash + Send + Sync + 'static>(
+        &mut self,
+        value: T,
+        assign: impl Fn(InternHandle) -> anyhow::Result<()> + Send + Sync + 'static,
+    ) {
+        let mut bytes = HashedBytes::default();
+        value.hash(&mut bytes);
+
+        let assign: RecordedAssign = Arc::new(assign);
+        self.recorded
+            .calls
+            .push(RecordedCall::PushTag(Arc::new(bytes), assign.clone()));
+        self.inner.push_tag(value, move |h| assign(h));
+    }
+
+    fn push_tag_prehashed(
+        &mut self,
+        precomputed: u64,
+        assign: impl Fn(InternHandle) -> anyhow::Result<()> + Send + Sync + 'static,
+    ) {
+        let assign: RecordedAssign = Arc::new(assign);
+        self.recorded
+            .calls
+            .push(RecordedCall::PushTagPrehashed(precomputed, assign.clone()));
+        self.inner
+            .push_tag_prehashed(precomputed, move |h| assign(h));
+    }
+
+    fn set_level_flags(&mut self, flags: LevelFlags) {
+        self.recorded.calls.push(RecordedCall::SetLevelFlags(flags));
+        self.inner.set_level_flags(flags);
+    }
+
+    fn set_data(&mut self, data: u64) {
+        self.recorded.calls.push(RecordedCall::SetData(data));
+        self.inner.set_data(data);
+    }
+
+    fn interner(&mut self) -> InternResult {
+        self.recorded.calls.push(RecordedCall::Interner);
+        self.inner.interner()
+    }
+
+    fn family(&self) -> &'static str {
+        self.inner.family()
+    }
 }
 
 impl Linker<CrcInterner> {
@@ -37,11 +192,98 @@ impl Linker<EntityInterner<CrcInterner>> {
     }
 }
 
+#[cfg(feature = "crc-interner")]
+impl Linker<BoxedInterner> {
+    /// Returns a new linker w/ an interner selected by name, see `BoxedInterner::from_name`,
+    ///
+    /// **Note** Unlike `new_crc`, a resource level is not pushed. Levels that are re-configured w/ a new interner (i.e.
+    /// by `link_deterministic`) are re-configured w/ a new interner of the same name.
+    ///
+    pub fn new_named(interner_name: &str) -> anyhow::Result<Self> {
+        let new_interner = BoxedInterner::named_factory(interner_name);
+
+        Ok(Self {
+            interner: BoxedInterner::from_name(interner_name)?,
+            levels: vec![],
+            pushed: vec![],
+            new_interner,
+            cached: None,
+        })
+    }
+}
+
 impl<I: InternerFactory + Default> Linker<I> {
     /// Constructs and returns a new representation,
     ///
+    /// **Note** The repr is cached until the level handles of this linker change, so linking the same levels again
+    /// returns the cached repr w/o re-linking each level. If the tail of the cached repr has been removed from the
+    /// handles table, the levels are re-linked.
+    ///
     pub fn link(&mut self) -> anyhow::Result<Repr> {
-        let tail = self.levels.iter().try_fold(
+        let key = self.levels_key();
+        if let Some((cached_key, repr)) = self.cached {
+            let (_, tail) = repr.tail.node();
+            if cached_key == key && HANDLES.contains(&tail) {
+                // Record the repr on the current span so events can be correlated w/ the repr
+                #[cfg(feature = "tracing-provenance")]
+                tracing::Span::current().record("repr", repr.as_u64());
+
+                return Ok(repr);
+            }
+        }
+
+        let repr = Self::link_levels(&self.levels)?;
+        self.cached = Some((key, repr));
+        Ok(repr)
+    }
+
+    /// Returns a hash of the handle of each level of this linker,
+    ///
+    fn levels_key(&self) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        for level in self.levels.iter() {
+            level.create_value.hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// Returns a new interner to re-configure levels w/,
+    ///
+    fn new_interner(&self) -> I {
+        self.new_interner.map(|f| f()).unwrap_or_default()
+    }
+
+    /// Re-configures and links the current levels w/ a specified entropy,
+    ///
+    /// The thread's entropy is restored once linking completes, even if linking fails. Since the levels are
+    /// re-configured w/ a new interner, equivalent linkers produce identical reprs regardless of the entropy of
+    /// the current thread.
+    ///
+    /// **Note** Resolving the levels of the returned repr requires the same entropy, since previous levels are
+    /// looked up w/ the entropy of the current thread.
+    ///
+    pub fn link_deterministic(&mut self, entropy: u64) -> anyhow::Result<Repr> {
+        let _guard = EntropyGuard::new(entropy);
+
+        let mut interner = self.new_interner();
+        let levels = self
+            .pushed
+            .iter()
+            .map(|l| {
+                Ok(Tag::new(
+                    &HANDLES,
+                    Arc::new(configure_level(l, &mut interner)?),
+                ))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Self::link_levels(&levels)
+    }
+
+    /// Links each level handle to the next and returns the representation of the tail,
+    ///
+    fn link_levels(levels: &[Tag<InternHandle, Arc<InternHandle>>]) -> anyhow::Result<Repr> {
+        let tail = levels.iter().try_fold(
             Tag::new(&HANDLES, Arc::new(InternHandle::default())),
             |from, to| {
                 let _ = from.link(to)?;
@@ -53,32 +295,218 @@ impl<I: InternerFactory + Default> Linker<I> {
         let tail = tail.value();
 
         if let Some(tail) = HANDLES.copy(&tail) {
-            Ok(Repr { tail })
+            let repr = Repr { tail };
+
+            // Record the repr on the current span so events can be correlated w/ the repr
+            #[cfg(feature = "tracing-provenance")]
+            tracing::Span::current().record("repr", repr.as_u64());
+
+            #[cfg(feature = "type-index")]
+            if let Some(root) = levels.first() {
+                crate::repr::resource::index_repr(repr, root.value());
+            }
+
+            Ok(repr)
         } else {
             Err(anyhow::anyhow!("Could not create representation"))
         }
     }
 
+    /// Computes the representation the current levels would produce w/o linking any handles,
+    ///
+    /// Each pushed level is re-configured w/ a new interner while a dry run is in progress, so no values are assigned
+    /// to any intern table and the handles are not linked.
+    ///
+    /// **Note** The tags of the returned repr will not be resolvable unless the repr has been linked,
+    /// however the handle and level structure is valid for ordering checks. Since levels are configured w/ a new
+    /// interner, interners that assign additional data (i.e. entity ids) are not reflected in the result.
+    ///
+    /// **Note** Levels are configured w/ the interner of this linker when they are pushed, so the tag values of each
+    /// level have already been assigned. A dry run only skips linking the level handles together.
+    ///
+    pub fn dry_run(&self) -> anyhow::Result<Repr> {
+        let mut handles = self.dry_run_handles()?;
+
+        match (handles.pop(), handles.pop()) {
+            (Some(mut tail), prev) => {
+                tail.link = prev.unwrap_or_default().register() ^ tail.register();
+                Ok(Repr { tail })
+            }
+            _ => Err(anyhow::anyhow!("Could not create representation")),
+        }
+    }
+
+    /// Returns the handle of each pushed level, re-configured w/ a new interner in a dry run,
+    ///
+    fn dry_run_handles(&self) -> anyhow::Result<Vec<InternHandle>> {
+        let _guard = DryRunGuard::new();
+
+        let mut interner = self.new_interner();
+        self.pushed
+            .iter()
+            .map(|l| l.configure(&mut interner))
+            .collect()
+    }
+
     /// Pushes a level to the current stack of levels,
     ///
+    /// **Error** Returns an error if the level cannot be configured, or if the level is not the next level of the
+    /// current stack of levels.
+    ///
     pub fn push_level(&mut self, level: impl Level) -> anyhow::Result<()> {
-        // Configure a new handle
-        let handle = level.configure(&mut self.interner)?;
+        // Configure a new handle, recording the calls made to the interner
+        let mut interner = RecordingInterner {
+            inner: &mut self.interner,
+            recorded: RecordedLevel::default(),
+        };
+        let handle = configure_level(&level, &mut interner)?;
+        let recorded = interner.recorded;
 
         // Handle errors
-        if let Some(last) = self.levels.last() {
-            let flag = last.create_value.level_flags();
+        Self::check_next_level(
+            self.levels.last().map(|l| l.create_value.level_flags()),
+            handle.level_flags(),
+        )?;
 
-            if flag != LevelFlags::from_bits_truncate(handle.level_flags().bits() >> 1) {
+        // Push the level to the stack
+        self.levels.push(Tag::new(&HANDLES, Arc::new(handle)));
+        self.pushed.push(recorded);
+
+        Ok(())
+    }
+
+    /// Pushes a host level w/ an address derived from the path of the node level pushed to this linker,
+    ///
+    /// **Note** See `HostLevel::from_path` for how the address is derived.
+    ///
+    /// **Error** Returns an error if the current level of this linker is not a node level, if the node level does not
+    /// have a path, or if an address cannot be derived from the path.
+    ///
+    pub fn push_host_from_path(&mut self) -> anyhow::Result<()> {
+        let Some(node) = self
+            .levels
+            .last()
+            .map(|l| *l.create_value)
+            .filter(|l| l.level_flags() == LevelFlags::LEVEL_2)
+        else {
+            return Err(anyhow::anyhow!("Expected a node level to be pushed"));
+        };
+
+        let Some(path) = NodeRepr(node).path() else {
+            return Err(anyhow::anyhow!("Node level {node:x?} does not have a path"));
+        };
+
+        self.push_level(HostLevel::from_path(path.as_str())?)
+    }
+
+    /// Pops the last level from the current stack of levels, returning the handle of the popped level,
+    ///
+    /// **Note** Levels that have already been linked remain linked.
+    ///
+    pub fn pop_level(&mut self) -> Option<InternHandle> {
+        let level = self.levels.pop()?;
+        self.pushed.pop();
+
+        Some(*level.create_value)
+    }
+
+    /// Pushes levels equivalent to the levels of a foreign repr above from_level, configured w/ this linker's interner,
+    ///
+    /// This allows the upper levels of a repr built separately (i.e. node and host levels) to be grafted onto a linker.
+    /// Each grafted level is checked in a dry run before any level is pushed, so a level that cannot be grafted leaves
+    /// this linker unchanged.
+    ///
+    /// **Note** A foreign repr is a repr linked w/ a different entropy than the current thread, i.e. by another runtime
+    /// or by `link_deterministic`. Since the handle of a node or host level does not depend on the levels below it, a
+    /// level handle can only be linked to a single chain per entropy, so the levels of a repr linked w/ the entropy of
+    /// the current thread are already linked to the chain of that repr. Entity reprs are not supported, since the
+    /// entropy of a repr is read from the data of its tail.
+    ///
+    /// **Error** Returns an error if repr was linked w/ the entropy of the current thread, if the current level of this
+    /// linker is not from_level, if a level above from_level cannot be grafted, or if a grafted level is already linked
+    /// to a different chain. Only node and host levels can be grafted.
+    ///
+    pub fn extend_from_foreign_repr(
+        &mut self,
+        repr: Repr,
+        from_level: LevelFlags,
+    ) -> anyhow::Result<()> {
+        if repr.tail.data == crate::entropy::ENTROPY.get() {
+            Err(anyhow::anyhow!(
+                "Cannot graft {repr:?}, since it was linked w/ the entropy of the current thread"
+            ))?;
+        }
+
+        let current = self.levels.last().map(|l| l.create_value.level_flags());
+        if current != Some(from_level) {
+            Err(anyhow::anyhow!(
+                "Expected the linker to be at level {from_level:?}, found {current:?}"
+            ))?;
+        }
+
+        // The levels of repr are resolved w/ the entropy repr was linked with, which is stored in the data of the tail
+        let levels = {
+            let _guard = EntropyGuard::new(repr.tail.data);
+            repr.get_levels()
+        };
+
+        let mut prev = self.levels.last().map(|l| *l.create_value);
+        let mut grafted = vec![];
+        for level in levels.into_iter().filter(|l| l.level_flags() > from_level) {
+            // Record the grafted level in a dry run, so that the level can be checked before any values are assigned
+            let _guard = DryRunGuard::new();
+            let mut interner = self.new_interner();
+            let mut interner = RecordingInterner {
+                inner: &mut interner,
+                recorded: RecordedLevel::default(),
+            };
+            let handle = match level.level_flags() {
+                LevelFlags::LEVEL_2 => NodeRepr(level).to_level().configure(&mut interner)?,
+                LevelFlags::LEVEL_3 => match HostRepr(level).to_level() {
+                    Some(host) => host.configure(&mut interner)?,
+                    None => Err(anyhow::anyhow!(
+                        "Could not resolve the host level of {repr:?}"
+                    ))?,
+                },
+                flags => Err(anyhow::anyhow!("Cannot graft a level w/ flags {flags:?}"))?,
+            };
+
+            // Check that the grafted level will link to the previous level
+            Self::check_next_level(prev.map(|p| p.level_flags()), handle.level_flags())?;
+            if let Some(prev) = prev {
+                let link = prev.register() ^ handle.register();
+                if HANDLES
+                    .copy(&handle)
+                    .is_some_and(|linked| linked.link != link)
+                {
+                    Err(anyhow::anyhow!(
+                        "Level {handle:x?} is already linked to a different chain"
+                    ))?;
+                }
+            }
+
+            prev = Some(handle);
+            grafted.push(interner.recorded);
+        }
+
+        for level in grafted {
+            self.push_level(level)?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the level flags of the next level follow the level flags of the last level,
+    ///
+    fn check_next_level(last: Option<LevelFlags>, next: LevelFlags) -> anyhow::Result<()> {
+        if let Some(last) = last {
+            if last != LevelFlags::from_bits_truncate(next.bits() >> 1) {
                 Err(anyhow::anyhow!("Expected next level"))?;
             }
-        } else if handle.level_flags() != LevelFlags::ROOT {
+        } else if next != LevelFlags::ROOT {
             Err(anyhow::anyhow!("Expected root level"))?;
         }
 
-        // Push the level to the stack
-        self.levels.push(Tag::new(&HANDLES, Arc::new(handle)));
-
         Ok(())
     }
 
@@ -102,9 +530,104 @@ impl<I: InternerFactory + Default> Linker<I> {
     }
 }
 
+/// Linker that caches the configured handle of each level so that only modified levels are re-configured,
+///
+/// When a level is replaced w/ `set_level`, that level and every level above it are re-configured on the next
+/// call to `link`, while the handles of the unchanged lower levels are reused.
+///
+#[derive(Default)]
+pub struct IncrementalLinker<I>
+where
+    I: InternerFactory,
+{
+    /// Interner,
+    ///
+    interner: I,
+    /// Levels that have been pushed to this linker,
+    ///
+    levels: Vec<Box<dyn PushedLevel<I>>>,
+    /// Configured handles of the clean prefix of levels,
+    ///
+    handles: Vec<InternHandle>,
+    /// Number of levels configured by the last call to `link`,
+    ///
+    reconfigured: usize,
+}
+
+impl<I: InternerFactory + Default> IncrementalLinker<I> {
+    /// Pushes a level to the current stack of levels,
+    ///
+    /// **Note** The level is configured on the next call to `link`.
+    ///
+    pub fn push_level(&mut self, level: impl Level + Send + Sync + 'static) {
+        self.levels.push(Box::new(level));
+    }
+
+    /// Replaces the level at index, marking it and every level above it as dirty,
+    ///
+    /// **Error** Returns an error if a level has not been pushed at index.
+    ///
+    pub fn set_level(
+        &mut self,
+        index: usize,
+        level: impl Level + Send + Sync + 'static,
+    ) -> anyhow::Result<()> {
+        let Some(current) = self.levels.get_mut(index) else {
+            return Err(anyhow::anyhow!("Level {index} has not been pushed"));
+        };
+
+        *current = Box::new(level);
+        self.handles.truncate(index);
+        Ok(())
+    }
+
+    /// Configures any dirty levels and links the representation,
+    ///
+    pub fn link(&mut self) -> anyhow::Result<Repr> {
+        self.reconfigured = 0;
+
+        // Re-configure each level after the clean prefix
+        for level in self.levels[self.handles.len()..].iter() {
+            let handle = level.configure_level(&mut self.interner)?;
+            Linker::<I>::check_next_level(
+                self.handles.last().map(|h| h.level_flags()),
+                handle.level_flags(),
+            )?;
+            self.handles.push(handle);
+            self.reconfigured += 1;
+        }
+
+        let levels = self
+            .handles
+            .iter()
+            .map(|h| Tag::new(&HANDLES, Arc::new(*h)))
+            .collect::<Vec<_>>();
+
+        Linker::<I>::link_levels(&levels)
+    }
+
+    /// Returns the configured handles of the clean prefix of levels,
+    ///
+    #[inline]
+    pub fn handles(&self) -> &[InternHandle] {
+        &self.handles
+    }
+
+    /// Returns the number of levels that were configured by the last call to `link`,
+    ///
+    #[inline]
+    pub fn reconfigured(&self) -> usize {
+        self.reconfigured
+    }
+}
+
 #[allow(unused)]
 mod tests {
     use super::Linker;
+    use crate::prelude::*;
+
+    use crate::macros::test_field;
+    use crate::repr::HANDLES;
 
     #[test]
     fn test_entity_crc() {
@@ -118,7 +641,416 @@ mod tests {
 
         eprintln!("{:x?}", a);
         eprintln!("{:x?}", b);
+    }
+
+    #[test]
+    fn test_is_entity() {
+        struct Test;
+
+        let entity = Linker::new_entity_crc::<Test>().link().unwrap();
+        assert!(entity.is_entity());
+        assert_eq!(Some(1), entity.entity_id());
+        assert_eq!(entity.entity_id(), entity.as_entity());
+
+        let crc = Linker::new_crc::<Test>().link().unwrap();
+        assert!(!crc.is_entity());
+        assert_eq!(None, crc.entity_id());
+    }
+
+    #[test]
+    fn test_same_entity() {
+        struct Test;
+        struct Other;
+
+        // Links an entity repr of Test for each id up to count
+        let link = |entropy: u64, count: usize| {
+            std::thread::spawn(move || {
+                crate::entropy::ENTROPY.set(entropy);
+
+                let mut linker = Linker::new_entity_crc::<Test>();
+                let mut reprs = vec![linker.link().unwrap()];
+                for _ in 1..count {
+                    // Re-pushing the root level w/ the same interner assigns the next id
+                    linker.levels.clear();
+                    linker.pushed.clear();
+                    linker.push_level(ResourceLevel::new::<Test>()).unwrap();
+                    reprs.push(linker.link().unwrap());
+                }
+                reprs
+            })
+            .join()
+            .unwrap()
+        };
+
+        let a = link(0x0a, 1);
+        let b = link(0x0b, 2);
+        assert_ne!(a[0], b[0]);
+
+        assert!(a[0].same_entity(&b[0]));
+        assert!(b[0].same_entity(&a[0]));
+        assert!(!a[0].same_entity(&b[1]));
+
+        // Reprs of different levels w/ the same id are not the same entity
+        let other = Linker::new_entity_crc::<Other>().link().unwrap();
+        assert!(!a[0].same_entity(&other));
+
+        let crc = Linker::new_crc::<Test>().link().unwrap();
+        assert!(!crc.same_entity(&crc));
+    }
+
+    #[test]
+    #[cfg(feature = "tracing-provenance")]
+    #[tracing_test::traced_test]
+    fn test_link_provenance() {
+        struct Test;
+
+        let span = tracing::info_span!("link", repr = tracing::field::Empty);
+        let _entered = span.enter();
+
+        let repr = Linker::new_crc::<Test>().link().unwrap();
+        tracing::info!("linked");
+
+        assert!(logs_contain(&format!("repr={}", repr.as_u64())));
+    }
+
+    #[test]
+    fn test_dry_run() {
+        use crate::interner::INTERNER_FAMILY;
+        use crate::repr::node::INPUT;
+
+        struct Test;
+
+        test_field!(Test, 0, "dry_run");
+
+        let mut linker = Test::linker::<CrcInterner>().unwrap();
+        linker
+            .push_level(NodeLevel::new().with_input("dry run input"))
+            .unwrap();
+
+        let dry = linker.dry_run().unwrap();
+
+        // The tags of each level are assigned when the level is pushed, but the dry run should not have linked the tail
+        let (_, tail) = dry.tail.node();
+        assert!(INPUT.contains(&tail));
+        assert!(INTERNER_FAMILY.contains(&tail));
+        assert!(!HANDLES.contains(&tail));
+
+        let repr = linker.link().unwrap();
+        assert_eq!(repr, dry);
+        assert!(HANDLES.contains(&tail));
+        assert_eq!(
+            "dry run input",
+            repr.as_node().unwrap().input().unwrap().as_str()
+        );
+
+        // Levels are re-configured w/ a new interner that does not assign entity ids
+        struct Entity;
+        let mut linker = Linker::new_entity_crc::<Entity>();
+        let dry = linker.dry_run().unwrap();
+        assert_eq!(dry, linker.dry_run().unwrap());
+        assert_eq!(dry, linker.link().unwrap());
+        assert_eq!(Some(1), dry.entity_id());
+    }
+
+    #[test]
+    fn test_incremental_linker() {
+        struct Test;
+
+        test_field!(Test, 0, "incremental");
+
+        let mut linker = IncrementalLinker::<CrcInterner>::default();
+        linker.push_level(ResourceLevel::new::<String>());
+        linker.push_level(FieldLevel::new::<0, Test>());
+        linker.push_level(NodeLevel::new().with_input("0"));
+
+        let first = linker.link().unwrap();
+        assert_eq!(3, linker.reconfigured());
+        let prefix = linker.handles()[..2].to_vec();
+
+        for i in 1..3 {
+            linker
+                .set_level(2, NodeLevel::new().with_input(i.to_string()))
+                .unwrap();
+
+            let repr = linker.link().unwrap();
+            assert_eq!(1, linker.reconfigured());
+            assert_eq!(prefix, linker.handles()[..2]);
+            assert_ne!(first, repr);
+            assert_eq!(
+                i.to_string(),
+                repr.as_node().unwrap().input().unwrap().as_str()
+            );
+            assert_eq!(Some("incremental"), repr.field_name());
+        }
+
+        // Linking w/o changes does not re-configure any levels
+        linker.link().unwrap();
+        assert_eq!(0, linker.reconfigured());
+
+        assert!(linker.set_level(3, HostLevel::new("test://")).is_err());
+    }
+
+    #[test]
+    fn test_link_deterministic() {
+        struct Test;
+
+        let link = |entropy: u64| {
+            std::thread::spawn(move || {
+                crate::entropy::ENTROPY.set(entropy);
+
+                let mut linker = Linker::new_crc::<Test>();
+                linker
+                    .push_level(DependencyLevel::new("deterministic"))
+                    .unwrap();
+                let repr = linker.link_deterministic(7).unwrap();
+
+                // Entropy is restored after linking
+                assert_eq!(entropy, crate::entropy::ENTROPY.get());
+
+                let ambient = linker.link().unwrap();
+                (repr, ambient)
+            })
+            .join()
+            .unwrap()
+        };
+
+        let (a, a_ambient) = link(0x0101);
+        let (b, b_ambient) = link(0x0202);
+
+        assert_eq!(a, b);
+        assert_eq!(a.tail.data, b.tail.data);
+        assert_ne!(a_ambient.tail.data, b_ambient.tail.data);
+    }
+
+    #[test]
+    fn test_link_mixed_interner_family() {
+        struct Test;
+
+        let mut repr = Linker::new_crc::<Test>().link().unwrap();
+
+        let err = repr
+            .upgrade(
+                EntityInterner::<CrcInterner>::default(),
+                DependencyLevel::new("mixed family"),
+            )
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Trying to link an intern handle created by"));
+
+        // Levels created by the same family can still be linked
+        repr.upgrade(CrcInterner::default(), DependencyLevel::new("mixed family"))
+            .unwrap();
+        assert_eq!(
+            "mixed family",
+            repr.as_dependency().unwrap().name().unwrap().as_str()
+        );
+    }
+
+    #[test]
+    fn test_extend_from_foreign_repr() {
+        struct Source;
+        struct Target;
+
+        test_field!(Source, 0, "graft_source");
+        test_field!(Target, 0, "graft_target");
+
+        let source = |input: &'static str, address: &'static str| {
+            let mut source = Source::linker::<CrcInterner>().unwrap();
+            source
+                .push_level(
+                    NodeLevel::new()
+                        .with_symbol("graft")
+                        .with_input(input)
+                        .with_doc_headers(vec!["Grafted header"]),
+                )
+                .unwrap();
+            source.push_level(HostLevel::new(address)).unwrap();
+            source
+        };
+
+        // Reprs linked w/ the entropy of the current thread cannot be grafted
+        let same_entropy = source("grafted w/ same entropy", "test://graft/same")
+            .link()
+            .unwrap();
+        let mut linker = Target::linker::<CrcInterner>().unwrap();
+        let target = linker.link().unwrap();
+        assert!(linker
+            .extend_from_foreign_repr(same_entropy, LevelFlags::LEVEL_1)
+            .is_err());
+        assert_eq!(1, linker.level());
+        assert_eq!(target, linker.link().unwrap());
+
+        // Reprs built on the same thread can be grafted if linked w/ a different entropy
+        let source = source("grafted input", "test://graft")
+            .link_deterministic(0x2511)
+            .unwrap();
+        assert!(linker
+            .extend_from_foreign_repr(source, LevelFlags::LEVEL_2)
+            .is_err());
+        linker
+            .extend_from_foreign_repr(source, LevelFlags::LEVEL_1)
+            .unwrap();
+        let combined = linker.link().unwrap();
+
+        assert_eq!(4, combined.get_levels().len());
+        assert_eq!(Some("graft_target"), combined.field_name());
+        let node = combined.as_node().unwrap();
+        assert_eq!("graft", node.symbol().unwrap().as_str());
+        assert_eq!("grafted input", node.input().unwrap().as_str());
+        assert_eq!(vec!["Grafted header"], *node.doc_headers().unwrap());
+        assert_eq!(
+            "test://graft",
+            combined.as_host().unwrap().address().unwrap().as_str()
+        );
+
+        // Grafted levels can only be linked to a single chain, so the linker is unchanged
+        let mut linker = Source::linker::<CrcInterner>().unwrap();
+        let err = linker
+            .extend_from_foreign_repr(source, LevelFlags::LEVEL_1)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("is already linked to a different chain"));
+        assert_eq!(1, linker.level());
+    }
+
+    #[test]
+    fn test_new_named() {
+        struct Named;
+        struct NamedCrc;
+
+        let mut linker = Linker::new_named("entity-crc").unwrap();
+        linker.push_level(ResourceLevel::new::<Named>()).unwrap();
+        let a = linker.link().unwrap();
+
+        // Entity ids are assigned when a level is configured, so re-push the level on the same interner
+        linker.levels.clear();
+        linker.pushed.clear();
+        linker.push_level(ResourceLevel::new::<Named>()).unwrap();
+        let b = linker.link().unwrap();
+
+        assert!(a.is_entity());
+        assert!(b.is_entity());
+        assert_ne!(a.tail.data(), b.tail.data());
+        assert_ne!(a.entity_id(), b.entity_id());
+
+        // Levels are re-configured w/ a new interner of the same name
+        let deterministic = linker.link_deterministic(0x2513).unwrap();
+        {
+            let _guard = crate::entropy::EntropyGuard::new(0x2513);
+            assert_eq!(Some(1), deterministic.entity_id());
+        }
+
+        // Boxed interners create the same handles as the inner interner
+        let mut linker = Linker::new_named("crc").unwrap();
+        linker.push_level(ResourceLevel::new::<NamedCrc>()).unwrap();
+        assert_eq!(
+            Linker::new_crc::<NamedCrc>().link().unwrap(),
+            linker.link().unwrap()
+        );
+
+        assert!(Linker::new_named("siphash").is_err());
+    }
+
+    #[test]
+    fn test_link_cached() {
+        use crate::repr::field::FIELD_NAME;
+
+        /// Crc interner that counts the handles it creates,
+        ///
+        #[derive(Default)]
+        struct Counting(CrcInterner, usize);
+
+        impl InternerFactory for Counting {
+            fn push_tag<T: std::hash::Hash + Send + Sync + 'static>(
+                &mut self,
+                value: T,
+                assign: impl Fn(InternHandle) -> anyhow::Result<()> + Send + Sync + 'static,
+            ) {
+                self.0.push_tag(value, assign);
+            }
+
+            fn set_level_flags(&mut self, flags: LevelFlags) {
+                self.0.set_level_flags(flags);
+            }
+
+            fn set_data(&mut self, data: u64) {
+                self.0.set_data(data);
+            }
+
+            fn interner(&mut self) -> InternResult {
+                self.1 += 1;
+                self.0.interner()
+            }
+
+            fn family(&self) -> &'static str {
+                self.0.family()
+            }
+        }
+
+        struct Configured;
+
+        test_field!(Configured, 0, "configured");
+
+        let mut linker = Linker::<Counting>::default();
+        linker
+            .push_level(ResourceLevel::new::<Configured>())
+            .unwrap();
+        assert_eq!(1, linker.interner.1);
+
+        // Linking does not configure any levels, and linking w/o changes returns the cached repr
+        let repr = linker.link().unwrap();
+        assert_eq!(1, linker.interner.1);
+        assert_eq!(Some((linker.levels_key(), repr)), linker.cached);
+        assert_eq!(repr, linker.link().unwrap());
+        assert_eq!(1, linker.interner.1);
+
+        // Levels are configured when pushed
+        linker
+            .push_level(FieldLevel::new::<0, Configured>())
+            .unwrap();
+        assert_eq!(2, linker.interner.1);
+        let field = linker.link().unwrap();
+        assert_eq!(2, linker.interner.1);
+        assert_eq!(Some((linker.levels_key(), field)), linker.cached);
+        assert_eq!(Some("configured"), field.field_name());
+
+        assert_eq!(
+            Some(LevelFlags::LEVEL_1),
+            linker.pop_level().map(|l| l.level_flags())
+        );
+        assert_eq!(repr, linker.link().unwrap());
+        assert_eq!(2, linker.interner.1);
+
+        // Linking again re-links levels that have been removed from the handles table
+        let (_, tail) = repr.tail.node();
+        assert!(HANDLES.remove(&tail).is_some());
+        assert_eq!(None, repr.downgrade_weak().upgrade());
+        assert_eq!(repr, linker.link().unwrap());
+        assert_eq!(Some(repr), repr.downgrade_weak().upgrade());
+    }
+
+    #[test]
+    fn test_push_host_from_path() {
+        struct HostPath;
+
+        test_field!(HostPath, 0, "host_path");
+
+        let mut linker = HostPath::linker::<CrcInterner>().unwrap();
+        assert!(linker.push_host_from_path().is_err());
+
+        linker
+            .push_level(NodeLevel::new().with_path("app/engine:prod"))
+            .unwrap();
+        linker.push_host_from_path().unwrap();
+        let repr = linker.link().unwrap();
+
+        let address = repr.as_host().unwrap().address().unwrap();
+        assert_eq!("app://engine?tag=prod", address.as_str());
 
-        ()
+        let host = HostLevel::from_path("app/engine/v2").unwrap();
+        assert_eq!("app://engine/v2", host.mount().0.as_str());
+        assert!(HostLevel::from_path("engine").is_err());
     }
 }
@@ -0,0 +1,424 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use xxhash_rust::xxh3::Xxh3;
+
+use crate::prelude::*;
+
+/// Resolved content of a repr used for content based comparisons,
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct ReprContent {
+    /// Resource type name,
+    ///
+    type_name: Option<&'static str>,
+    /// Field name,
+    ///
+    field_name: Option<&'static str>,
+    /// Field offset,
+    ///
+    field_offset: Option<usize>,
+    /// Node input,
+    ///
+    input: Option<Arc<String>>,
+    /// Host address,
+    ///
+    address: Option<Arc<String>>,
+}
+
+impl ReprContent {
+    /// Resolves the content of a repr,
+    ///
+    pub(crate) fn resolve(repr: &Repr) -> Self {
+        let field = repr.as_field();
+
+        Self {
+            type_name: repr.as_resource().and_then(|r| r.type_name()),
+            field_name: field.as_ref().and_then(|f| f.name()),
+            field_offset: field.as_ref().and_then(|f| f.offset()),
+            input: repr.as_node().and_then(|n| n.input()),
+            address: repr.as_host().and_then(|h| h.address()),
+        }
+    }
+}
+
+impl Repr {
+    /// Feeds the resolved content of this repr into a hasher,
+    ///
+    /// **Note** Unlike the derived `Hash` impl, the raw handle is not hashed so reprs w/ the same content
+    /// hash the same regardless of the entropy they were created with.
+    ///
+    pub fn hash_into<H: Hasher>(&self, state: &mut H) {
+        ReprContent::resolve(self).hash(state);
+    }
+
+    /// Returns a hash of the resolved content of this repr,
+    ///
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash_into(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Returns a 128-bit fingerprint of the resolved tags of every level of this repr,
+    ///
+    /// **Note** Like `content_hash` the raw handles are not hashed, so the fingerprint is independent of the
+    /// entropy the repr was created with. Nested reprs (i.e. dependency parents, receiver fields and host extensions)
+    /// contribute their own fingerprint.
+    ///
+    pub fn fingerprint(&self) -> u128 {
+        let mut w = FingerprintWriter(Xxh3::new());
+        for level in self.get_levels() {
+            w.u16(level.level_flags().bits());
+
+            // Resource tags
+            w.opt(level.resource_type_name(), FingerprintWriter::str);
+            w.opt(level.resource_type_size(), FingerprintWriter::usize);
+            w.opt(level.resource_parse_type_name(), FingerprintWriter::str);
+            w.opt(level.resource_ffi_type_name(), FingerprintWriter::str);
+
+            // Field tags
+            w.opt(level.owner_name(), FingerprintWriter::str);
+            w.opt(level.owner_size(), FingerprintWriter::usize);
+            w.opt(level.field_offset(), FingerprintWriter::usize);
+            w.opt(level.field_name(), FingerprintWriter::str);
+
+            // Dependency, variant and receiver tags
+            w.opt(level.dependency_name(), |w, n| w.str(&n));
+            w.opt(level.dependency_parent(), |w, p| w.u128(p.fingerprint()));
+            w.opt(level.variant_name(), |w, n| w.str(&n));
+            w.opt(level.variant_discriminant(), FingerprintWriter::u64);
+            w.opt(level.recv_name(), |w, n| w.str(&n));
+            w.opt(level.recv_fields(), |w, f| w.reprs(&f));
+
+            // Node tags
+            w.opt(level.symbol(), |w, s| w.str(&s));
+            w.opt(level.input(), |w, i| w.str(&i));
+            w.opt(level.tag(), |w, t| w.str(&t));
+            w.opt(level.path(), |w, p| w.str(&p));
+            w.opt(level.node_idx(), FingerprintWriter::usize);
+            w.opt(level.node_source(), |w, s| w.str(&s));
+            w.opt(level.doc_headers(), |w, d| {
+                w.usize(d.len());
+                d.iter().for_each(|h| w.str(h));
+            });
+            w.opt(level.annotations(), |w, a| {
+                w.usize(a.len());
+                a.iter().for_each(|(k, v)| {
+                    w.str(k);
+                    w.str(v);
+                });
+            });
+            w.opt(level.source_span(), |w, s| {
+                w.usize(s.start);
+                w.usize(s.end);
+            });
+            w.opt(level.source_relative(), |w, p| {
+                w.bytes(p.as_os_str().as_encoded_bytes())
+            });
+            w.opt(level.timestamp(), FingerprintWriter::u64);
+            w.opt(level.payload(), |w, p| w.bytes(&p));
+
+            // Host tags
+            w.opt(level.host_address(), |w, a| w.str(&a));
+            w.opt(level.host_extensions(), |w, e| w.reprs(&e));
+        }
+        w.0.digest128()
+    }
+
+    /// Returns the number of leading levels w/ the same register in both reprs,
+    ///
+    pub fn common_prefix_len(&self, other: &Repr) -> usize {
+        self.get_levels()
+            .iter()
+            .zip(other.get_levels().iter())
+            .take_while(|(a, b)| a.register() == b.register())
+            .count()
+    }
+
+    /// Returns the index of the first level where this repr and other diverge, and a human-readable reason,
+    ///
+    /// **Note** Returns None if the reprs are identical up to the length of the shorter repr.
+    ///
+    pub fn divergence(&self, other: &Repr) -> Option<(usize, String)> {
+        let index = self.common_prefix_len(other);
+        let level = self.get_levels().get(index).copied()?;
+        let other_level = other.get_levels().get(index).copied()?;
+
+        let reason = describe_level(&level)
+            .into_iter()
+            .zip(describe_level(&other_level))
+            .find(|((_, a), (_, b))| a != b)
+            .map(|((name, a), (_, b))| match (a, b) {
+                (Some(a), Some(b)) => format!("{name} changed from '{a}' to '{b}'"),
+                (Some(a), None) => format!("{name} '{a}' was removed"),
+                (None, Some(b)) => format!("{name} '{b}' was added"),
+                (None, None) => unreachable!("values differ"),
+            })
+            .unwrap_or_else(|| format!("level {index} changed"));
+
+        Some((index, reason))
+    }
+}
+
+/// Returns the name and display value of the tags of a level that are used to describe a divergence,
+///
+fn describe_level(level: &InternHandle) -> [(&'static str, Option<String>); 12] {
+    [
+        (
+            "resource type name",
+            level.resource_type_name().map(str::to_string),
+        ),
+        ("field name", level.field_name().map(str::to_string)),
+        ("field offset", level.field_offset().map(|o| o.to_string())),
+        (
+            "dependency name",
+            level.dependency_name().map(|n| n.to_string()),
+        ),
+        ("variant name", level.variant_name().map(|n| n.to_string())),
+        ("receiver name", level.recv_name().map(|n| n.to_string())),
+        ("node symbol", level.symbol().map(|s| s.to_string())),
+        ("node input", level.input().map(|i| i.to_string())),
+        ("node tag", level.tag().map(|t| t.to_string())),
+        ("node path", level.path().map(|p| p.to_string())),
+        ("node idx", level.node_idx().map(|i| i.to_string())),
+        ("host address", level.host_address().map(|a| a.to_string())),
+    ]
+}
+
+/// Writes the tags of a level into a fingerprint hasher w/ a fixed encoding,
+///
+/// **Note** Integers are written as fixed-width little-endian bytes and variable length values are prefixed w/ their
+/// length, so the fingerprint does not depend on the std `Hash` impls, which are not stable across platforms or
+/// releases. Optional values are prefixed w/ a presence byte.
+///
+struct FingerprintWriter(Xxh3);
+
+impl FingerprintWriter {
+    /// Writes a presence byte, followed by the value if present,
+    ///
+    fn opt<T>(&mut self, value: Option<T>, write: impl FnOnce(&mut Self, T)) {
+        match value {
+            Some(value) => {
+                self.0.update(&[1]);
+                write(self, value);
+            }
+            None => self.0.update(&[0]),
+        }
+    }
+
+    fn u16(&mut self, value: u16) {
+        self.0.update(&value.to_le_bytes());
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.0.update(&value.to_le_bytes());
+    }
+
+    fn u128(&mut self, value: u128) {
+        self.0.update(&value.to_le_bytes());
+    }
+
+    /// Writes a usize as a u64, so the encoding does not depend on the pointer width,
+    ///
+    fn usize(&mut self, value: usize) {
+        self.u64(value as u64);
+    }
+
+    /// Writes the length of the bytes, followed by the bytes,
+    ///
+    fn bytes(&mut self, value: &[u8]) {
+        self.usize(value.len());
+        self.0.update(value);
+    }
+
+    fn str(&mut self, value: &str) {
+        self.bytes(value.as_bytes());
+    }
+
+    /// Writes the number of reprs, followed by the fingerprint of each repr,
+    ///
+    fn reprs(&mut self, reprs: &[Repr]) {
+        self.usize(reprs.len());
+        for repr in reprs {
+            self.u128(repr.fingerprint());
+        }
+    }
+}
+
+/// Wrapper over a repr that compares and hashes by resolved content rather than by handle,
+///
+/// **Note** Content is resolved when the key is created, since resolving the levels of a repr depends on
+/// the entropy of the thread that created it.
+///
+#[derive(Debug, Clone)]
+pub struct ContentKey {
+    /// Wrapped repr,
+    ///
+    repr: Repr,
+    /// Resolved content of the repr,
+    ///
+    content: ReprContent,
+}
+
+impl From<Repr> for ContentKey {
+    fn from(repr: Repr) -> Self {
+        Self {
+            content: ReprContent::resolve(&repr),
+            repr,
+        }
+    }
+}
+
+impl Deref for ContentKey {
+    type Target = Repr;
+
+    fn deref(&self) -> &Self::Target {
+        &self.repr
+    }
+}
+
+impl Hash for ContentKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.content.hash(state);
+    }
+}
+
+impl PartialEq for ContentKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.content == other.content
+    }
+}
+
+impl Eq for ContentKey {}
+
+impl PartialOrd for ContentKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ContentKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.content.cmp(&other.content)
+    }
+}
+
+#[allow(unused)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::prelude::*;
+
+    use crate::macros::test_field;
+
+    struct Test;
+
+    test_field!(Test, 0, "test");
+
+    #[cfg(feature = "crc-interner")]
+    fn build(entropy: u64, input: &'static str) -> (Repr, ContentKey) {
+        std::thread::spawn(move || {
+            crate::entropy::ENTROPY.set(entropy);
+
+            let mut linker = Test::linker::<CrcInterner>().unwrap();
+            linker
+                .push_level(NodeLevel::new().with_input(input))
+                .unwrap();
+            linker.push_level(HostLevel::new("test://content")).unwrap();
+            let repr = linker.link().unwrap();
+
+            (repr, ContentKey::from(repr))
+        })
+        .join()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let fingerprint = |entropy: u64, input: &'static str| {
+            std::thread::spawn(move || {
+                crate::entropy::ENTROPY.set(entropy);
+
+                let mut linker = Test::linker::<CrcInterner>().unwrap();
+                linker
+                    .push_level(NodeLevel::new().with_input(input))
+                    .unwrap();
+                linker
+                    .push_level(HostLevel::new("test://fingerprint"))
+                    .unwrap();
+                linker.link().unwrap().fingerprint()
+            })
+            .join()
+            .unwrap()
+        };
+
+        let a = fingerprint(4, "fingerprint");
+        let b = fingerprint(5, "fingerprint");
+        let c = fingerprint(6, "other fingerprint");
+        let d = fingerprint(7, "");
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_ne!(a, d);
+        assert_ne!(c, d);
+
+        // The fingerprint only depends on the encoded tags, so it is stable across processes and platforms
+        let resource = Linker::new_crc::<u64>().link().unwrap();
+        assert_eq!(
+            0x65fc_328c_c348_c05e_6b25_9719_71ea_2706,
+            resource.fingerprint()
+        );
+    }
+
+    #[test]
+    fn test_content_key() {
+        let (a, a_key) = build(1, "content");
+        let (b, b_key) = build(2, "content");
+        let (c, c_key) = build(3, "other content");
+
+        assert_ne!(a, b);
+        assert_eq!(a_key, b_key);
+        assert_ne!(a_key, c_key);
+        assert_eq!(a, *a_key);
+
+        let mut map = HashMap::new();
+        map.insert(a_key, "a");
+        map.insert(b_key, "b");
+        map.insert(c_key, "c");
+        assert_eq!(2, map.len());
+    }
+
+    #[test]
+    fn test_divergence() {
+        let node = |input: &str| {
+            let mut linker = Test::linker::<CrcInterner>().unwrap();
+            linker
+                .push_level(NodeLevel::new().with_input(input))
+                .unwrap();
+            linker.link().unwrap()
+        };
+
+        let a = node("divergence a");
+        let b = node("divergence b");
+
+        assert_eq!(2, a.common_prefix_len(&b));
+        assert_eq!(
+            Some((
+                2,
+                "node input changed from 'divergence a' to 'divergence b'".to_string()
+            )),
+            a.divergence(&b)
+        );
+
+        // Identical up to the length of the shorter repr
+        let field = Test::linker::<CrcInterner>().unwrap().link().unwrap();
+        assert_eq!(2, a.common_prefix_len(&field));
+        assert_eq!(None, a.divergence(&field));
+        assert_eq!(None, a.divergence(&a));
+    }
+}
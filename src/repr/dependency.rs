@@ -1,5 +1,8 @@
 use std::sync::Arc;
 
+use serde::Deserialize;
+use serde::Serialize;
+
 use crate::define_intern_table;
 use crate::prelude::*;
 use crate::push_tag;
@@ -66,9 +69,19 @@ impl Level for DependencyLevel {
 
 /// Wrapper struct with access to dependency tags,
 ///
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Serialize, Deserialize)]
 pub struct DependencyRepr(pub(crate) InternHandle);
 
 impl DependencyRepr {
+    /// Returns the repr whose tail is this level,
+    ///
+    /// **Note** If this level has not been linked, the returned repr only contains this level.
+    ///
+    #[inline]
+    pub fn to_repr(&self) -> Repr {
+        Repr::from_level(self.0)
+    }
+
     /// Returns the name of this dependency,
     ///
     #[inline]
@@ -82,4 +95,73 @@ impl DependencyRepr {
     pub fn parent(&self) -> Option<Repr> {
         self.0.dependency_parent()
     }
+
+    /// Returns a dependency level w/ the name and parent of this dependency,
+    ///
+    /// **Note** Returns None if the name of this dependency could not be resolved.
+    ///
+    pub fn to_level(&self) -> Option<DependencyLevel> {
+        Some(DependencyLevel {
+            parent: self
+                .parent()
+                .map(|p| Tag::new(&DEPENDENCY_PARENT, Arc::new(p))),
+            name: Tag::new(&DEPENDENCY_NAME, self.name()?),
+        })
+    }
+
+    /// Returns true if other has the same name as this dependency but a different parent,
+    ///
+    pub fn same_name_different_parent(&self, other: &DependencyRepr) -> bool {
+        match (self.name(), other.name()) {
+            (Some(name), Some(other_name)) if name == other_name => self.parent() != other.parent(),
+            _ => false,
+        }
+    }
+
+    /// Returns a dependency level w/ the same name as this dependency rebased onto a new parent,
+    ///
+    /// **Note** Returns None if the name of this dependency could not be resolved.
+    ///
+    pub fn rebase(&self, parent: Repr) -> Option<DependencyLevel> {
+        self.name()
+            .map(|name| DependencyLevel::new(name.as_str()).with_parent(parent))
+    }
+}
+
+#[allow(unused)]
+mod tests {
+    use crate::prelude::*;
+
+    struct Test;
+
+    fn dependency(name: &str, parent: Repr) -> Repr {
+        let mut linker = Linker::new_crc::<Test>();
+        linker
+            .push_level(DependencyLevel::new(name).with_parent(parent))
+            .unwrap();
+        linker.link().unwrap()
+    }
+
+    #[test]
+    fn test_same_name_different_parent() {
+        struct ParentA;
+        struct ParentB;
+
+        let parent_a = Linker::new_crc::<ParentA>().link().unwrap();
+        let parent_b = Linker::new_crc::<ParentB>().link().unwrap();
+
+        let a = dependency("dep", parent_a).as_dependency().unwrap();
+        let b = dependency("dep", parent_b).as_dependency().unwrap();
+        let c = dependency("other", parent_b).as_dependency().unwrap();
+
+        assert!(a.same_name_different_parent(&b));
+        assert!(!a.same_name_different_parent(&a));
+        assert!(!a.same_name_different_parent(&c));
+
+        let mut linker = Linker::new_crc::<Test>();
+        linker.push_level(a.rebase(parent_b).unwrap()).unwrap();
+        let rebased = linker.link().unwrap().as_dependency().unwrap();
+        assert_eq!(b.0, rebased.0);
+        assert!(!b.same_name_different_parent(&rebased));
+    }
 }
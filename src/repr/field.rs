@@ -1,6 +1,10 @@
 use std::any::TypeId;
+use std::collections::BTreeMap;
 use std::str::FromStr;
 
+use serde::Deserialize;
+use serde::Serialize;
+
 use crate::define_intern_table;
 use crate::prelude::*;
 use crate::push_tag;
@@ -75,19 +79,19 @@ pub trait Field<const OFFSET: usize>: Send + Sync + 'static {
 pub struct FieldLevel {
     /// Owner type id,
     ///
-    owner_type_id: Tag<TypeId>,
+    owner_type_id: Tag<TypeId, TypeId>,
     /// Owner type name,
     ///
-    owner_name: Tag<&'static str>,
+    owner_name: Tag<&'static str, &'static str>,
     /// Owner size,
     ///
-    owner_size: Tag<usize>,
+    owner_size: Tag<usize, usize>,
     /// Field offset,
     ///
-    field_offset: Tag<usize>,
+    field_offset: Tag<usize, usize>,
     /// Field name,
     ///
-    field_name: Tag<&'static str>,
+    field_name: Tag<&'static str, &'static str>,
 }
 
 impl FieldLevel {
@@ -98,11 +102,11 @@ impl FieldLevel {
         Owner: Field<OFFSET> + Send + Sync + 'static,
     {
         Self {
-            owner_type_id: Tag::new(&OWNER_ID, std::any::TypeId::of::<Owner>),
-            owner_name: Tag::new(&OWNER_NAME, std::any::type_name::<Owner>),
-            owner_size: Tag::new(&OWNER_SIZE, std::mem::size_of::<Owner>),
-            field_offset: Tag::new(&FIELD_OFFSET, || OFFSET),
-            field_name: Tag::new(&FIELD_NAME, Owner::field_name),
+            owner_type_id: Tag::new(&OWNER_ID, std::any::TypeId::of::<Owner>()),
+            owner_name: Tag::new(&OWNER_NAME, std::any::type_name::<Owner>()),
+            owner_size: Tag::new(&OWNER_SIZE, std::mem::size_of::<Owner>()),
+            field_offset: Tag::new(&FIELD_OFFSET, OFFSET),
+            field_name: Tag::new(&FIELD_NAME, Owner::field_name()),
         }
     }
 }
@@ -136,9 +140,33 @@ impl Level for FieldLevel {
 
 /// Wrapper struct to access field tags,
 ///
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Serialize, Deserialize)]
 pub struct FieldRepr(pub(crate) InternHandle);
 
 impl FieldRepr {
+    /// Returns the repr whose tail is this level,
+    ///
+    /// **Note** If this level has not been linked, the returned repr only contains this level.
+    ///
+    #[inline]
+    pub fn to_repr(&self) -> Repr {
+        Repr::from_level(self.0)
+    }
+
+    /// Returns a field level w/ the tags of this field,
+    ///
+    /// **Note** Returns None if any of the owner or field tags of this field could not be resolved.
+    ///
+    pub fn to_level(&self) -> Option<FieldLevel> {
+        Some(FieldLevel {
+            owner_type_id: Tag::new(&OWNER_ID, self.owner_type_id()?),
+            owner_name: Tag::new(&OWNER_NAME, self.owner_name()?),
+            owner_size: Tag::new(&OWNER_SIZE, self.owner_size()?),
+            field_offset: Tag::new(&FIELD_OFFSET, self.offset()?),
+            field_name: Tag::new(&FIELD_NAME, self.name()?),
+        })
+    }
+
     /// Returns the tag value of the field name,
     ///
     #[inline]
@@ -174,3 +202,77 @@ impl FieldRepr {
         self.0.owner_type_id()
     }
 }
+
+/// Groups fields by the name of their owner, w/ each group sorted by field offset,
+///
+/// **Note** Fields w/o an owner name are grouped under `"<unknown>"`.
+///
+pub fn group_by_owner(fields: &[FieldRepr]) -> BTreeMap<&'static str, Vec<FieldRepr>> {
+    let mut groups = BTreeMap::<&'static str, Vec<FieldRepr>>::new();
+
+    for field in fields {
+        groups
+            .entry(field.owner_name().unwrap_or("<unknown>"))
+            .or_default()
+            .push(FieldRepr(field.0));
+    }
+
+    for group in groups.values_mut() {
+        group.sort_by_key(|f| f.offset());
+    }
+
+    groups
+}
+
+#[allow(unused)]
+mod tests {
+    use crate::prelude::*;
+
+    use crate::macros::test_field;
+
+    use super::group_by_owner;
+
+    struct A;
+    struct B;
+
+    test_field!(A, 0, "a0");
+    test_field!(A, 1, "a1");
+    test_field!(B, 0, "b0");
+
+    #[cfg(feature = "crc-interner")]
+    fn field_repr<const OFFSET: usize, Owner: Field<OFFSET>>() -> FieldRepr {
+        Owner::linker::<CrcInterner>()
+            .unwrap()
+            .link()
+            .unwrap()
+            .as_field()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_group_by_owner() {
+        let fields = vec![
+            field_repr::<1, A>(),
+            field_repr::<0, B>(),
+            field_repr::<0, A>(),
+            FieldRepr(InternHandle::default()),
+        ];
+
+        let groups = group_by_owner(&fields);
+        assert_eq!(3, groups.len());
+
+        let a = &groups[std::any::type_name::<A>()];
+        assert_eq!(
+            vec![Some("a0"), Some("a1")],
+            a.iter().map(|f| f.name()).collect::<Vec<_>>()
+        );
+
+        let b = &groups[std::any::type_name::<B>()];
+        assert_eq!(
+            vec![Some("b0")],
+            b.iter().map(|f| f.name()).collect::<Vec<_>>()
+        );
+
+        assert_eq!(1, groups["<unknown>"].len());
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::BTreeSet;
 use std::sync::Arc;
 
 use serde::Deserialize;
@@ -6,6 +7,7 @@ use serde::Serialize;
 use crate::define_intern_table;
 use crate::prelude::*;
 use crate::push_tag;
+use crate::repr::namespace::namespace_tag;
 
 // Intern table for address values
 define_intern_table!(ADDRESS: String);
@@ -38,6 +40,31 @@ impl HostLevel {
         }
     }
 
+    /// Creates a new host level w/ an address derived from a node path,
+    ///
+    /// The first segment of the path is the scheme of the address, and a `:` suffix is the tag of the address,
+    /// i.e. `app/engine:prod` is `app://engine?tag=prod`.
+    ///
+    /// **Error** Returns an error if the path does not have a scheme and a host segment.
+    ///
+    pub fn from_path(path: &str) -> anyhow::Result<Self> {
+        let Some((scheme, rest)) = path
+            .split_once('/')
+            .filter(|(s, r)| !s.is_empty() && !r.is_empty())
+        else {
+            return Err(anyhow::anyhow!(
+                "Cannot derive a host address from path {path}"
+            ));
+        };
+
+        let address = match rest.rsplit_once(':') {
+            Some((host, tag)) if !host.is_empty() => format!("{scheme}://{host}?tag={tag}"),
+            _ => format!("{scheme}://{rest}"),
+        };
+
+        Ok(Self::new(address))
+    }
+
     /// Sets extensions on the host,
     ///
     #[inline]
@@ -46,6 +73,15 @@ impl HostLevel {
     }
 }
 
+impl Namespace for HostLevel {
+    fn namespaced(&self, namespace: &str) -> Self {
+        Self {
+            address: namespace_tag(namespace, &self.address),
+            extensions: self.extensions.clone(),
+        }
+    }
+}
+
 impl Level for HostLevel {
     fn configure(&self, interner: &mut impl InternerFactory) -> InternResult {
         push_tag!(dyn interner, &self.address);
@@ -76,6 +112,27 @@ impl Level for HostLevel {
 pub struct HostRepr(pub(crate) InternHandle);
 
 impl HostRepr {
+    /// Returns the repr whose tail is this level,
+    ///
+    /// **Note** If this level has not been linked, the returned repr only contains this level.
+    ///
+    #[inline]
+    pub fn to_repr(&self) -> Repr {
+        Repr::from_level(self.0)
+    }
+
+    /// Returns a host level w/ the address and extensions of this host,
+    ///
+    /// **Note** Returns None if the address of this host could not be resolved.
+    ///
+    pub fn to_level(&self) -> Option<HostLevel> {
+        let mut level = HostLevel::new(self.address()?.as_str());
+        if let Some(extensions) = self.extensions() {
+            level.set_extensions(extensions.to_vec());
+        }
+        Some(level)
+    }
+
     /// Returns the address provided by the host,
     ///
     #[inline]
@@ -90,6 +147,19 @@ impl HostRepr {
         self.0.host_extensions()
     }
 
+    /// Returns an iterator over every repr under this host and the depth of each repr, starting w/ this host at depth 0,
+    ///
+    /// The reprs are visited depth-first, the children of a repr are the fields of its receiver level followed by the
+    /// extensions of its host level, so nested hosts are walked as well. Each repr is only visited once, which
+    /// guards against cycles.
+    ///
+    pub fn walk(&self) -> impl Iterator<Item = (usize, LevelView)> {
+        Walk {
+            stack: vec![(0, self.to_repr())],
+            visited: BTreeSet::new(),
+        }
+    }
+
     /// Finds the repr of a field owned by receiver,
     ///
     pub fn find_extension(&self, name: &str) -> Option<Repr> {
@@ -113,3 +183,107 @@ impl HostRepr {
         }
     }
 }
+
+/// Depth-first iterator over the reprs under a host, see `HostRepr::walk`,
+///
+struct Walk {
+    /// Reprs left to visit and their depth,
+    ///
+    stack: Vec<(usize, Repr)>,
+    /// Reprs that have already been visited,
+    ///
+    visited: BTreeSet<Repr>,
+}
+
+impl Iterator for Walk {
+    type Item = (usize, LevelView);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((depth, repr)) = self.stack.pop() {
+            if !self.visited.insert(repr) {
+                continue;
+            }
+
+            let fields = repr
+                .as_recv()
+                .filter(|r| r.name().is_some())
+                .and_then(|r| r.fields());
+            let extensions = repr.as_host().and_then(|h| h.extensions());
+
+            let children = fields
+                .iter()
+                .chain(extensions.iter())
+                .flat_map(|c| c.iter())
+                .copied()
+                .collect::<Vec<_>>();
+            self.stack
+                .extend(children.into_iter().rev().map(|c| (depth + 1, c)));
+
+            return Some((depth, repr.view()));
+        }
+
+        None
+    }
+}
+
+#[allow(unused)]
+mod tests {
+    use crate::prelude::*;
+
+    use crate::macros::test_field;
+
+    struct Walked;
+
+    impl Recv for Walked {
+        fn symbol() -> &'static str {
+            "walked"
+        }
+    }
+
+    test_field!(Walked, 0, "walked");
+
+    #[cfg(feature = "crc-interner")]
+    fn host(input: &str, address: &str, extensions: Vec<Repr>) -> Repr {
+        let mut linker = Walked::linker::<CrcInterner>().unwrap();
+        linker
+            .push_level(NodeLevel::new().with_input(input))
+            .unwrap();
+        let mut host = HostLevel::new(address);
+        host.set_extensions(extensions);
+        linker.push_level(host).unwrap();
+        linker.link().unwrap()
+    }
+
+    #[test]
+    fn test_walk() {
+        let field = Walked::linker::<CrcInterner>().unwrap().link().unwrap();
+        let recv =
+            Walked::link_recv(NodeLevel::new().with_symbol("walked recv"), vec![field]).unwrap();
+
+        let inner = host("walk inner", "test://walk/inner", vec![recv]);
+        let outer = host("walk outer", "test://walk/outer", vec![inner, recv]);
+
+        let walked = outer
+            .as_host()
+            .unwrap()
+            .walk()
+            .map(|(depth, view)| (depth, view.kind()))
+            .collect::<Vec<_>>();
+
+        // The recv is only visited once, under the inner host
+        assert_eq!(
+            vec![
+                (0, ReprKind::Host),
+                (1, ReprKind::Host),
+                (2, ReprKind::Node),
+                (3, ReprKind::Field),
+            ],
+            walked
+        );
+
+        let Some((_, LevelView::Host(root))) = outer.as_host().unwrap().walk().next() else {
+            panic!("expected the walk to start at the host");
+        };
+        assert_eq!("test://walk/outer", root.address().unwrap().as_str());
+    }
+}
@@ -1,34 +1,94 @@
+pub(crate) mod content;
 pub(crate) mod dependency;
 pub(crate) mod field;
 pub(crate) mod host;
+pub(crate) mod namespace;
 pub(crate) mod node;
 pub(crate) mod recv;
+pub(crate) mod relink;
+pub(crate) mod report;
+pub(crate) mod resolved;
 pub(crate) mod resource;
+pub(crate) mod schema;
+pub(crate) mod snapshot;
+pub(crate) mod value;
+pub(crate) mod variant;
+pub(crate) mod weak;
 
 pub mod prelude {
+    pub use super::all_reprs;
+    pub use super::interner_report;
+    pub use super::seal_all;
+    pub use super::unseal_all;
+    pub use super::LevelView;
     pub use super::Repr;
+    pub use super::ReprKind;
 
+    pub use super::content::ContentKey;
+
+    pub use super::report::InternerReport;
+    pub use super::report::TableReport;
+
+    pub use super::resolved::ResolvedRepr;
+    pub use super::resolved::ResolvedTagBundle;
+
+    pub use super::snapshot::restore;
+    pub use super::snapshot::restore_with;
+    pub use super::snapshot::restore_with_limits;
+    pub use super::snapshot::snapshot;
+    pub use super::snapshot::snapshot_with;
+    pub use super::snapshot::Bincode;
+    #[cfg(feature = "cbor")]
+    pub use super::snapshot::Cbor;
+    pub use super::snapshot::Compression;
+    pub use super::snapshot::ExportFormat;
+    pub use super::snapshot::DEFAULT_MAX_ENTRIES;
+    pub use super::snapshot::DEFAULT_MAX_ENTRY_BYTES;
+
+    pub use super::schema::OwnedField;
+    pub use super::schema::OwnedSchema;
+    pub use super::schema::MAX_LEAKED_BYTES;
+
+    pub use super::resource::FfiInfo;
+    pub use super::resource::FfiStatus;
     pub use super::resource::ResourceLevel;
     pub use super::resource::ResourceRepr;
     pub use super::resource::FFI;
 
+    #[cfg(feature = "type-index")]
+    pub use super::resource::reprs_of_type;
+
+    pub use super::field::group_by_owner;
     pub use super::field::Field;
     pub use super::field::FieldLevel;
     pub use super::field::FieldRepr;
 
+    pub use super::recv::FieldDiff;
     pub use super::recv::Recv;
     pub use super::recv::RecvLevel;
     pub use super::recv::RecvRepr;
 
+    pub use super::node::NodeInfo;
     pub use super::node::NodeLevel;
     pub use super::node::NodeRepr;
 
     pub use super::dependency::DependencyLevel;
     pub use super::dependency::DependencyRepr;
 
+    pub use super::value::ResolveValue;
+    pub use super::value::ValueRegistry;
+
+    pub use super::variant::VariantLevel;
+    pub use super::variant::VariantRepr;
+
+    pub use super::weak::WeakRepr;
+
     pub use super::host::HostLevel;
     pub use super::host::HostRepr;
 
+    pub use super::namespace::Namespace;
+    pub use super::namespace::Namespaced;
+
     pub type FieldName = &'static str;
     pub type FieldHelp = String;
     pub type FFIType = &'static str;
@@ -39,11 +99,22 @@ use crate::prelude::*;
 use anyhow::anyhow;
 use serde::Deserialize;
 use serde::Serialize;
+use std::collections::BTreeSet;
 use std::fmt::Display;
+use std::str::FromStr;
 use std::sync::Arc;
+use tracing::warn;
 
 use self::host::HostRepr;
 
+/// Scheme of uris returned by `Repr::to_uri`,
+///
+const URI_SCHEME: &str = "runir://";
+
+/// Width node inputs are truncated to by `Repr::summary`,
+///
+const DEFAULT_SUMMARY_WIDTH: usize = 48;
+
 // Intern table for intern handles
 define_intern_table!(HANDLES: InternHandle);
 
@@ -60,6 +131,10 @@ define_intern_table!(HANDLES: InternHandle);
 /// a repr factory. This allows the repr to store and pass around a single u64 value
 /// which can be used to query interned tags from each level.
 ///
+/// **Note** Cloning a repr, or any of the wrapper reprs (i.e. `NodeRepr`), only copies the intern handle. Interned
+/// values are never cloned, so values resolved from a clone share the same allocation as values resolved from the
+/// original.
+///
 #[derive(
     Hash, Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
 )]
@@ -69,6 +144,237 @@ pub struct Repr {
     pub(crate) tail: InternHandle,
 }
 
+/// Enumeration of the most specific populated level of a repr,
+///
+/// **Note** There is only a kind for each level defined by this crate. This crate does not define an array level, so
+/// there is no `Array` kind, a repr w/ an array level defined by a downstream crate is classified as `Unknown`.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ReprKind {
+    /// Tail is a resource level,
+    ///
+    Resource,
+    /// Tail is a field level,
+    ///
+    Field,
+    /// Tail is a receiver level,
+    ///
+    Recv,
+    /// Tail is a dependency level,
+    ///
+    Dependency,
+    /// Tail is a variant level,
+    ///
+    Variant,
+    /// Tail is a node level,
+    ///
+    Node,
+    /// Tail is a host level,
+    ///
+    Host,
+    /// Tail is a level that is not defined by this crate,
+    ///
+    Unknown,
+}
+
+/// Typed view of the tail level of a repr,
+///
+pub enum LevelView {
+    /// Tail is a resource level,
+    ///
+    Resource(ResourceRepr),
+    /// Tail is a field level,
+    ///
+    Field(FieldRepr),
+    /// Tail is a receiver level,
+    ///
+    Recv(RecvRepr),
+    /// Tail is a dependency level,
+    ///
+    Dependency(DependencyRepr),
+    /// Tail is a variant level,
+    ///
+    Variant(VariantRepr),
+    /// Tail is a node level,
+    ///
+    Node(NodeRepr),
+    /// Tail is a host level,
+    ///
+    Host(HostRepr),
+    /// Tail is a level that is not defined by this crate,
+    ///
+    Unknown(Repr),
+}
+
+impl LevelView {
+    /// Returns the kind of the viewed level,
+    ///
+    pub fn kind(&self) -> ReprKind {
+        match self {
+            LevelView::Resource(_) => ReprKind::Resource,
+            LevelView::Field(_) => ReprKind::Field,
+            LevelView::Recv(_) => ReprKind::Recv,
+            LevelView::Dependency(_) => ReprKind::Dependency,
+            LevelView::Variant(_) => ReprKind::Variant,
+            LevelView::Node(_) => ReprKind::Node,
+            LevelView::Host(_) => ReprKind::Host,
+            LevelView::Unknown(_) => ReprKind::Unknown,
+        }
+    }
+}
+
+/// Returns every linked repr that is the tail of its chain in the `HANDLES` table,
+///
+/// Reprs that are a prefix of another linked repr are skipped, as are reprs w/ the same canonical handle as a repr that
+/// was already returned, see `InternHandle::canonical`.
+///
+/// **Note** Previous levels are resolved w/ the entropy of the current thread, so chains created by a thread
+/// w/ a different entropy will not be recognized as a prefix.
+///
+pub fn all_reprs() -> Vec<Repr> {
+    let entries = HANDLES.entries();
+
+    // Level handles that are the previous level of some linked handle
+    let prefixes = entries
+        .iter()
+        .filter_map(|(_, linked)| linked.node().0)
+        .collect::<BTreeSet<_>>();
+
+    let mut canonical = BTreeSet::new();
+    entries
+        .iter()
+        .filter(|(level, linked)| linked.is_node() && !prefixes.contains(level))
+        .map(|(_, linked)| Repr { tail: **linked })
+        .filter(|repr| canonical.insert(repr.tail.canonical()))
+        .collect()
+}
+
+/// Evaluates body w/ each intern table populated when linking a repr,
+///
+macro_rules! for_each_table {
+    (|$table:ident| $body:expr) => {
+        for_each_table!(@each |$table| $body;
+            HANDLES,
+            crate::entity::ENTITY,
+            crate::interner::INTERNER_FAMILY,
+            resource::TYPE_NAME,
+            resource::TYPE_SIZE,
+            resource::TYPE_ID,
+            resource::TRAIT_NAME,
+            resource::PARSE_TYPE_NAME,
+            resource::PARSE_TYPE_ID,
+            resource::FFI_TYPE_NAME,
+            resource::FFI_INFO,
+            #[cfg(feature = "util-clap")]
+            resource::FFI_VALUE_PARSER,
+            field::OWNER_ID,
+            field::OWNER_NAME,
+            field::OWNER_SIZE,
+            field::FIELD_OFFSET,
+            field::FIELD_NAME,
+            recv::RECV_NAMES,
+            recv::RECV_FIELDS,
+            recv::RECV_CONTENT_ADDRESSED,
+            dependency::DEPENDENCY_NAME,
+            dependency::DEPENDENCY_PARENT,
+            variant::VARIANT_NAME,
+            variant::VARIANT_DISCRIMINANT,
+            node::SYMBOL,
+            node::INPUT,
+            node::TAG,
+            node::PATH,
+            node::NODE_IDX,
+            node::BLOCK_IDX,
+            node::SOURCE,
+            node::DOC_HEADERS,
+            node::ANNOTATIONS,
+            node::SOURCE_SPAN,
+            node::SOURCE_RELATIVE,
+            node::TIMESTAMP,
+            node::PAYLOAD,
+            host::ADDRESS,
+            host::EXTENSIONS,
+        )
+    };
+    (@each |$table:ident| $body:expr; $($(#[$cfg:meta])* $t:path),* $(,)?) => {
+        $(
+            $(#[$cfg])*
+            {
+                let $table = &$t;
+                $body;
+            }
+        )*
+    };
+}
+
+/// Returns a report of the entries in every intern table populated when linking a repr,
+///
+/// **Note** Tables defined outside of this crate are not included.
+///
+pub fn interner_report() -> InternerReport {
+    let mut report = InternerReport::default();
+    for_each_table!(|table| report.push(table));
+    report
+}
+
+/// Seals every intern table populated when linking a repr,
+///
+/// Once sealed, linking a new repr returns an error, which catches code that creates reprs after startup. Reprs that
+/// were linked before the tables were sealed can still be rebuilt, i.e. `Linker::new_crc` for a type linked at startup.
+///
+/// **Note** Tables defined outside of this crate are not sealed, see `InternTable::seal`.
+///
+pub fn seal_all() {
+    for_each_table!(|table| table.seal());
+}
+
+/// Unseals every intern table sealed by `seal_all`,
+///
+pub fn unseal_all() {
+    for_each_table!(|table| table.unseal());
+}
+
+/// Percent-encodes every byte of a uri path segment that is not unreserved, except for `:`,
+///
+fn percent_encode(segment: &str) -> String {
+    let mut encoded = String::with_capacity(segment.len());
+    for b in segment.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b':' => {
+                encoded.push(b as char)
+            }
+            _ => encoded.push_str(&format!("%{b:02X}")),
+        }
+    }
+    encoded
+}
+
+/// Decodes a percent-encoded uri path,
+///
+/// **Error** Returns an error if an escape is not followed by two hex digits, or if the decoded path is not utf8.
+///
+fn percent_decode(path: &str) -> anyhow::Result<String> {
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+
+    let mut idx = 0;
+    while idx < bytes.len() {
+        if bytes[idx] == b'%' {
+            let escape = path
+                .get(idx + 1..idx + 3)
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                .ok_or_else(|| anyhow!("Invalid escape in uri path {path}"))?;
+            decoded.push(escape);
+            idx += 3;
+        } else {
+            decoded.push(bytes[idx]);
+            idx += 1;
+        }
+    }
+
+    Ok(String::from_utf8(decoded)?)
+}
+
 impl From<u64> for Repr {
     fn from(value: u64) -> Self {
         Repr {
@@ -78,6 +384,16 @@ impl From<u64> for Repr {
 }
 
 impl Repr {
+    /// Returns the repr linked w/ a level handle as its tail,
+    ///
+    /// **Note** If the level has not been linked, the level handle is used as the tail.
+    ///
+    pub(crate) fn from_level(level: InternHandle) -> Repr {
+        Repr {
+            tail: HANDLES.copy(&level).unwrap_or(level),
+        }
+    }
+
     /// Returns as a u64 value,
     ///
     #[inline]
@@ -92,6 +408,43 @@ impl Repr {
         self.tail.entity()
     }
 
+    /// Returns the entity id value if this repr was built w/ an entity interner,
+    ///
+    /// **Note** Same as `as_entity`, named to pair w/ `is_entity`.
+    ///
+    #[inline]
+    pub fn entity_id(&self) -> Option<u64> {
+        self.as_entity()
+    }
+
+    /// Returns true if this repr was built w/ an entity interner,
+    ///
+    #[inline]
+    pub fn is_entity(&self) -> bool {
+        self.entity_id().is_some()
+    }
+
+    /// Returns true if both reprs are entity reprs of the same levels w/ the same entity id,
+    ///
+    /// Unlike `==`, the entropy of the threads that built each repr is not compared, so two entity reprs built by
+    /// different threads are the same entity if both were assigned the same id.
+    ///
+    /// **Note** Returns false if either repr is not an entity repr.
+    ///
+    pub fn same_entity(&self, other: &Repr) -> bool {
+        match (self.unscoped_entity_id(), other.unscoped_entity_id()) {
+            (Some(a), Some(b)) => a == b && self.as_u64() == other.as_u64(),
+            _ => false,
+        }
+    }
+
+    /// Returns the entity id assigned to the tail of this repr, regardless of the entropy it was built with,
+    ///
+    fn unscoped_entity_id(&self) -> Option<u64> {
+        let (_, current) = self.tail.node();
+        crate::entity::ENTITY.copy(&current)
+    }
+
     /// Returns repr as a uuid,
     ///
     #[inline]
@@ -99,6 +452,140 @@ impl Repr {
         uuid::Uuid::from_u64_pair(self.tail.as_u64(), 0)
     }
 
+    /// Returns the u64 value of this repr encoded as base58,
+    ///
+    #[inline]
+    pub fn to_base58(&self) -> String {
+        bs58::encode(self.as_u64().to_be_bytes()).into_string()
+    }
+
+    /// Decodes a repr from an id returned by `to_base58`,
+    ///
+    pub fn from_base58(id: &str) -> anyhow::Result<Repr> {
+        let bytes: [u8; 8] = bs58::decode(id)
+            .into_vec()?
+            .try_into()
+            .map_err(|_| anyhow!("Expected an 8 byte id, {id}"))?;
+
+        Ok(Repr::from(u64::from_be_bytes(bytes)))
+    }
+
+    /// Returns a uri for this repr in the format `runir://{resource_type}/{field_name}?id={base58}`,
+    ///
+    /// **Note** The path segments are hints for readers, only the id is used to resolve the repr.
+    /// Tags that are not present are written as `_`. Characters of a type name that are not allowed in a path segment
+    /// (i.e. `<`, `>`, `,` and spaces) are percent-encoded.
+    ///
+    pub fn to_uri(&self) -> String {
+        let (type_name, field_name) = self.uri_hints();
+
+        format!(
+            "{URI_SCHEME}{}/{}?id={}",
+            percent_encode(type_name),
+            percent_encode(field_name),
+            self.to_base58()
+        )
+    }
+
+    /// Resolves a repr from a uri returned by `to_uri`,
+    ///
+    /// The interner is the interner the repr is expected to have been configured w/, the repr is only resolved if the
+    /// tail level was configured by an interner of the same family, since the data value of the id has different
+    /// semantics for each family (i.e. an entity id vs. entropy).
+    ///
+    /// **Note** If the path hints do not match the resolved repr a warning is logged, but the repr
+    /// is still returned.
+    ///
+    /// **Error** Returns an error if the uri is malformed, if the id does not resolve to a linked repr, or if the tail
+    /// level was configured by an interner of a different family.
+    ///
+    pub fn from_uri(uri: &str, interner: &impl InternerFactory) -> anyhow::Result<Repr> {
+        let (path, id) = uri
+            .strip_prefix(URI_SCHEME)
+            .and_then(|rest| rest.split_once("?id="))
+            .ok_or_else(|| {
+                anyhow!("Expected a uri w/ the format {URI_SCHEME}{{path}}?id={{id}}")
+            })?;
+
+        let repr = Repr::from_base58(id)?;
+
+        let (_, level) = repr.tail.node();
+        if HANDLES.copy(&level) != Some(repr.tail) {
+            Err(anyhow!("Id {id} does not resolve to a linked repr"))?;
+        }
+
+        if let Some(family) = crate::interner::INTERNER_FAMILY.copy(&level) {
+            if family != interner.family() {
+                Err(anyhow!(
+                    "Id {id} was configured by {family}, expected {}",
+                    interner.family()
+                ))?;
+            }
+        }
+
+        let path = percent_decode(path)?;
+        let (type_name, field_name) = repr.uri_hints();
+        let expected = format!("{type_name}/{field_name}");
+        if path != expected {
+            warn!("Path hint {path} does not match {expected} for id {id}");
+        }
+
+        Ok(repr)
+    }
+
+    /// Returns the resource type name and field name hints of the uri for this repr,
+    ///
+    fn uri_hints(&self) -> (&'static str, &'static str) {
+        (
+            self.as_resource()
+                .and_then(|r| r.type_name())
+                .unwrap_or("_"),
+            self.field_name().unwrap_or("_"),
+        )
+    }
+
+    /// Returns a one-line summary of this repr,
+    ///
+    /// The summary is formatted as `{type_name} [{field_name}] {input} @{address}` w/ absent parts omitted.
+    ///
+    /// **Note** Node inputs are truncated to 48 characters, use `summary_truncated` to configure the width.
+    ///
+    #[inline]
+    pub fn summary(&self) -> String {
+        self.summary_truncated(DEFAULT_SUMMARY_WIDTH)
+    }
+
+    /// Returns a one-line summary of this repr w/ the node input truncated to width characters,
+    ///
+    /// **Note** A truncated input ends w/ an ellipsis, inputs that span multiple lines are truncated at the first line.
+    ///
+    pub fn summary_truncated(&self, width: usize) -> String {
+        let mut parts = vec![];
+
+        if let Some(type_name) = self.as_resource().and_then(|r| r.type_name()) {
+            parts.push(type_name.to_string());
+        }
+
+        if let Some(field_name) = self.field_name() {
+            parts.push(format!("[{field_name}]"));
+        }
+
+        if let Some(input) = self.as_node().and_then(|n| n.input()) {
+            let line = input.lines().next().unwrap_or_default();
+            let mut truncated = line.chars().take(width).collect::<String>();
+            if truncated.len() < input.len() {
+                truncated.push('…');
+            }
+            parts.push(truncated);
+        }
+
+        if let Some(address) = self.as_host().and_then(|h| h.address()) {
+            parts.push(format!("@{address}"));
+        }
+
+        parts.join(" ")
+    }
+
     /// Upgrades a representation in place w/ a new level,
     ///
     pub fn upgrade(
@@ -107,11 +594,12 @@ impl Repr {
         level: impl Level,
     ) -> anyhow::Result<()> {
         // Configure a new handle
-        let handle = level.configure(&mut interner)?;
+        let handle = crate::level::configure_level(&level, &mut interner)?;
 
         // TODO -- error handling
-        // 1) Need verify the interner factory is the same as what was previously used
-        // 2) Need to verify the next level is indeed the next level
+        // 1) Need to verify the next level is indeed the next level
+        //
+        // **Note** Linking verifies the interner family matches the family previously used
 
         let to = Tag::new(&HANDLES, Arc::new(handle));
 
@@ -123,6 +611,53 @@ impl Repr {
         Ok(())
     }
 
+    /// Returns a new representation w/ a node level appended,
+    ///
+    /// Unlike `upgrade`, the current representation is left unchanged.
+    ///
+    /// **Error** Returns an error if the current tail is not the level before the node level, the ordering is checked
+    /// before the node level is configured so that no tags are assigned for a node level that cannot be linked.
+    ///
+    pub fn push_node(
+        &self,
+        node: NodeLevel,
+        interner: &mut impl InternerFactory,
+    ) -> anyhow::Result<Repr> {
+        if self.tail.level_flags() != LevelFlags::LEVEL_1 {
+            return Err(anyhow!(
+                "Cannot push a node level onto {:?}, expected a field level",
+                self.tail.level_flags()
+            ));
+        }
+
+        let mut repr = *self;
+        repr.upgrade(interner, node)?;
+        Ok(repr)
+    }
+
+    /// Links a full repr from a resource of `T`, a field of `Owner`, a node w/ input and a host w/ address,
+    ///
+    /// **Note** This is a convenience over `Linker` for tests and simple uses, the parse and ffi types of the resource
+    /// level are set from the `Owner`'s field implementation.
+    ///
+    #[cfg(feature = "crc-interner")]
+    pub fn quick<T, const N: usize, Owner>(input: &str, address: &str) -> anyhow::Result<Repr>
+    where
+        T: Send + Sync + 'static,
+        Owner: Field<N>,
+    {
+        let mut resource = ResourceLevel::new::<T>();
+        resource.set_parse_type::<Owner::ParseType>();
+        resource.set_ffi::<Owner::FFIType>();
+
+        let mut linker = Linker::<CrcInterner>::default();
+        linker.push_level(resource)?;
+        linker.push_level(FieldLevel::new::<N, Owner>())?;
+        linker.push_level(NodeLevel::new().with_input(input))?;
+        linker.push_level(HostLevel::new(address))?;
+        linker.link()
+    }
+
     /// Downgrade the Repr by count,
     ///
     /// **Error** Returns an error if count exceeds current repr level
@@ -151,6 +686,94 @@ impl Repr {
         ))
     }
 
+    /// Returns the static skeleton of this repr, w/ the node level and every level above it removed,
+    ///
+    /// The skeleton only contains the resource and field levels, so the dynamic node tags (i.e. input, symbol,
+    /// annotations and source) are detached. This is essentially `downgrade_to(LevelFlags::LEVEL_1)`.
+    ///
+    /// **Error** Returns an error if this repr does not have a level after the root level.
+    ///
+    pub fn skeleton(&self) -> anyhow::Result<Repr> {
+        let levels = self.get_levels().len();
+        if levels < 2 {
+            Err(anyhow!("Could not create a skeleton of level {levels}"))?;
+        }
+
+        self.downgrade(levels - 2)
+    }
+
+    /// Returns the kind of the most specific populated level of this repr,
+    ///
+    /// **Note** Level 1 is shared by field, receiver, and dependency levels, so these are
+    /// distinguished by which tags are present.
+    ///
+    pub fn kind(&self) -> ReprKind {
+        let (_, tail) = self.tail.node();
+
+        match tail.level_flags() {
+            LevelFlags::ROOT => ReprKind::Resource,
+            LevelFlags::LEVEL_1 if tail.field_name().is_some() => ReprKind::Field,
+            LevelFlags::LEVEL_1 if tail.recv_name().is_some() => ReprKind::Recv,
+            LevelFlags::LEVEL_1 if tail.dependency_name().is_some() => ReprKind::Dependency,
+            LevelFlags::LEVEL_1 if tail.variant_name().is_some() => ReprKind::Variant,
+            LevelFlags::LEVEL_2 => ReprKind::Node,
+            LevelFlags::LEVEL_3 => ReprKind::Host,
+            _ => ReprKind::Unknown,
+        }
+    }
+
+    /// Returns a typed view of the tail level of this repr,
+    ///
+    pub fn view(&self) -> LevelView {
+        let (_, tail) = self.tail.node();
+
+        match self.kind() {
+            ReprKind::Resource => LevelView::Resource(ResourceRepr(tail)),
+            ReprKind::Field => LevelView::Field(FieldRepr(tail)),
+            ReprKind::Recv => LevelView::Recv(RecvRepr(tail)),
+            ReprKind::Dependency => LevelView::Dependency(DependencyRepr(tail)),
+            ReprKind::Variant => LevelView::Variant(VariantRepr(tail)),
+            ReprKind::Node => LevelView::Node(NodeRepr(tail)),
+            ReprKind::Host => LevelView::Host(HostRepr(tail)),
+            ReprKind::Unknown => LevelView::Unknown(*self),
+        }
+    }
+
+    /// Returns the index of the tail level of this repr, w/ the root level at index 0,
+    ///
+    /// **Note** Returns None if the tail does not have exactly one level flag.
+    ///
+    pub fn level_index(&self) -> Option<usize> {
+        LevelKind::try_from(self.tail.level_flags())
+            .ok()
+            .map(|k| k.index())
+    }
+
+    /// Returns the level kind of each level of this repr, ordered from the root to the tail,
+    ///
+    /// **Note** Levels that do not have exactly one level flag are skipped.
+    ///
+    pub fn level_path(&self) -> Vec<LevelKind> {
+        self.get_levels()
+            .iter()
+            .filter_map(|l| LevelKind::try_from(l.level_flags()).ok())
+            .collect()
+    }
+
+    /// Returns the nearest enclosing repr whose tail level matches flags,
+    ///
+    /// Levels are scanned from the tail toward the root, and the first matching level is
+    /// returned as the tail of a downgraded repr.
+    ///
+    pub fn nearest(&self, flags: LevelFlags) -> Option<Repr> {
+        let levels = self.get_levels();
+
+        levels
+            .iter()
+            .rposition(|l| l.level_flags() == flags)
+            .and_then(|idx| self.downgrade(levels.len() - 1 - idx).ok())
+    }
+
     /// Return a vector containing an intern handle pointing to each level of this representation,
     ///
     /// The vector is ordered w/ the first element as the root and the last as the tail.
@@ -175,6 +798,115 @@ impl Repr {
         }
     }
 
+    /// Returns the transitive closure of dependency parents for this representation,
+    ///
+    /// The vector is ordered starting w/ the direct parent. The walk stops when a repr
+    /// without a dependency parent is reached, or when a parent has already been visited.
+    ///
+    pub fn dependency_closure(&self) -> Vec<Repr> {
+        let mut closure = vec![];
+        let mut visited = BTreeSet::from([*self]);
+        let mut cursor = *self;
+
+        while let Some(parent) = cursor.as_dependency().and_then(|d| d.parent()) {
+            if !visited.insert(parent) {
+                break;
+            }
+
+            closure.push(parent);
+            cursor = parent;
+        }
+
+        closure
+    }
+
+    /// Returns true if other is in the transitive dependency closure of this representation,
+    ///
+    /// **Note** This can be used to check if adding other as a parent would create a cycle.
+    ///
+    pub fn depends_on(&self, other: &Repr) -> bool {
+        self.dependency_closure().contains(other)
+    }
+
+    /// Parses an input string into the parse type of this representation,
+    ///
+    /// **Error** Returns an error if `T` is not the parse type of this representation or if the input could not be parsed.
+    ///
+    pub fn parse_input<T>(&self, input: &str) -> anyhow::Result<T>
+    where
+        T: FromStr + 'static,
+        T::Err: std::error::Error + Send + Sync + 'static,
+    {
+        if !self.as_resource().is_some_and(|r| r.is_parse_type::<T>()) {
+            Err(anyhow!(
+                "Parse type of {:x?} is not {}",
+                self.tail,
+                std::any::type_name::<T>()
+            ))?;
+        }
+
+        Ok(input.parse::<T>()?)
+    }
+
+    /// Parses an input string into the parse type of this representation, returning the error as a message,
+    ///
+    /// Unlike `parse_input`, the error of `T::from_str` only needs to implement `Display`, which allows input to be
+    /// validated against a field's parse type w/o the `util-clap` value parser.
+    ///
+    /// **Error** Returns an error message if `T` is not the parse type of this representation or if the input could not be
+    /// parsed.
+    ///
+    pub fn parse_with<T>(&self, input: &str) -> Result<T, String>
+    where
+        T: FromStr + 'static,
+        T::Err: Display,
+    {
+        if !self.as_resource().is_some_and(|r| r.is_parse_type::<T>()) {
+            return Err(format!(
+                "Parse type of {:x?} is not {}",
+                self.tail,
+                std::any::type_name::<T>()
+            ));
+        }
+
+        T::from_str(input).map_err(|e| format!("Could not parse '{input}': {e}"))
+    }
+
+    /// Returns the resource repr of the owner of this field representation,
+    ///
+    /// The owner is found by looking up the resource levels tagged w/ the owner's type id, which resolves if the owner
+    /// has been linked as a resource w/ any interner, i.e. `Linker::new_crc::<Owner>().link()`.
+    ///
+    /// **Note** If the owner was linked as more than one resource level (i.e. w/ and w/o a parse type), resource levels
+    /// w/o a parse or ffi type are preferred.
+    ///
+    /// **Note** There is no reverse index from owner type id to resource levels, so each call scans every entry of the
+    /// `TYPE_ID` table, which is linear in the number of resource levels linked in the process. Callers resolving the
+    /// owner of many fields should resolve the owner once per owner type, i.e. per group of `group_by_owner`.
+    ///
+    pub fn owner_repr(&self) -> Option<Repr> {
+        let field = self.as_field()?;
+        let type_id = field.owner_type_id()?;
+        let name = field.owner_name()?;
+        let size = field.owner_size()?;
+
+        resource::TYPE_ID
+            .entries()
+            .into_iter()
+            .filter(|(h, id)| **id == type_id && h.level_flags() == LevelFlags::ROOT)
+            .map(|(h, _)| ResourceRepr(h))
+            .filter(|r| {
+                r.type_name() == Some(name) && r.type_size() == Some(size) && HANDLES.contains(&r.0)
+            })
+            .min_by_key(|r| {
+                (
+                    r.parse_type_name().is_some() || r.ffi_type_name().is_some(),
+                    r.0,
+                )
+            })
+            .map(|r| r.to_repr())
+    }
+
     /// Returns the repr as a resource repr,
     ///
     #[inline]
@@ -189,6 +921,16 @@ impl Repr {
         self.as_resource().and_then(|r| r.ffi_type_name())
     }
 
+    /// Validates that the ffi type of the resource repr is coherent w/ its parse type,
+    ///
+    /// **Note** Reprs w/o a resource level are not checked, see `ResourceRepr::validate_ffi_coherence`.
+    ///
+    #[inline]
+    pub fn validate_ffi_coherence(&self) -> anyhow::Result<()> {
+        self.as_resource()
+            .map_or(Ok(()), |r| r.validate_ffi_coherence())
+    }
+
     /// Returns the field help value derived from the node repr,
     ///
     #[inline]
@@ -198,6 +940,21 @@ impl Repr {
             .and_then(|d| d.first().cloned())
     }
 
+    /// Returns every doc header of the node repr joined into a multi-line description,
+    ///
+    /// **Note** Each header is trimmed of the `# --` comment prefix, the same as when the node repr is rendered.
+    ///
+    pub fn description(&self) -> Option<String> {
+        let docs = self.as_node().and_then(|r| r.doc_headers())?;
+
+        Some(
+            docs.iter()
+                .map(|d| node::trim_doc_header(d))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+
     /// Returns the field name value derived from the field repr,
     ///
     #[inline]
@@ -205,6 +962,28 @@ impl Repr {
         self.as_field().and_then(|r| r.name())
     }
 
+    /// Returns the ffi type metadata of the resource repr,
+    ///
+    #[inline]
+    pub fn ffi_info(&self) -> Option<std::sync::Arc<FfiInfo>> {
+        self.as_resource().and_then(|r| r.ffi_info())
+    }
+
+    /// Returns a description of the value expected by this field's value parser, i.e. `expected u32`,
+    ///
+    /// **Note** Derived from the ffi type of the resource repr, see `FFI::value_description`, returns None if the
+    /// resource does not have an ffi type.
+    ///
+    pub fn value_parser_description(&self) -> Option<String> {
+        let ffi_type = self.ffi_type()?;
+
+        Some(
+            self.ffi_info()
+                .map(|i| i.value_description.clone())
+                .unwrap_or_else(|| format!("expected {ffi_type}")),
+        )
+    }
+
     /// Returns the value parser for this field,
     ///
     #[inline]
@@ -217,7 +996,9 @@ impl Repr {
 
     /// Returns values for expressing this field as a cli argument,
     ///
-    #[inline]
+    /// **Note** If the ffi type of the field does not have a value parser, falls back to clap's default
+    /// string parser, or a bool parser for flag ffi types (see `FFI::is_flag`) so that the field can be used as a flag.
+    ///
     #[cfg(feature = "util-clap")]
     pub fn split_for_arg(
         &self,
@@ -234,6 +1015,18 @@ impl Repr {
             self.field_value_parser(),
         ) {
             (Some(a), b, Some(c), Some(d)) => Some((a, b, c, d)),
+            (Some(a), b, Some(c), None) => {
+                use clap::builder::IntoResettable;
+                use clap::builder::ValueParser;
+
+                warn!("Field {a} has no value parser for ffi type {c}, falling back to a default parser");
+                let value_parser = if self.ffi_info().is_some_and(|i| i.is_flag) {
+                    ValueParser::bool()
+                } else {
+                    ValueParser::string()
+                };
+                Some((a, b, c, value_parser.into_resettable()))
+            }
             _ => None,
         }
     }
@@ -246,6 +1039,13 @@ impl Repr {
         self.get_levels().get(1).copied().map(DependencyRepr)
     }
 
+    /// Returns the repr as a variant repr,
+    ///
+    #[inline]
+    pub fn as_variant(&self) -> Option<VariantRepr> {
+        self.get_levels().get(1).copied().map(VariantRepr)
+    }
+
     /// Returns the repr as a receiver repr,
     ///
     #[inline]
@@ -275,6 +1075,39 @@ impl Repr {
     }
 }
 
+impl Repr {
+    /// Renders the runmd sections of only the levels of this repr after the levels shared w/ base,
+    ///
+    /// This is useful when a repr was rendered and then upgraded, since only the new levels need to be rendered again.
+    ///
+    /// **Note** Levels are rendered in the same format as `{:#}`, see `Repr::common_prefix_len`.
+    ///
+    pub fn render_delta(&self, base: &Repr) -> String {
+        RunmdDelta {
+            repr: self,
+            from: self.common_prefix_len(base),
+        }
+        .to_string()
+    }
+}
+
+/// Displays the runmd sections of a repr starting at a level,
+///
+struct RunmdDelta<'a> {
+    /// Repr to display,
+    ///
+    repr: &'a Repr,
+    /// Index of the first level to display,
+    ///
+    from: usize,
+}
+
+impl Display for RunmdDelta<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        display_runmd_levels(self.repr, self.from, f)
+    }
+}
+
 impl Display for Repr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if f.alternate() {
@@ -289,11 +1122,33 @@ impl Display for Repr {
 }
 
 fn display_runmd(repr: &Repr, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    if let Some(node) = repr.as_node() {
+    display_runmd_levels(repr, 0, f)
+}
+
+/// Displays the runmd sections of each level of a repr, starting at the level at index `from`,
+///
+/// **Note** When the resource level is skipped, the first section is written as the header of the table.
+///
+fn display_runmd_levels(
+    repr: &Repr,
+    from: usize,
+    f: &mut std::fmt::Formatter<'_>,
+) -> std::fmt::Result {
+    let mut has_header = from == 0;
+    let mut section = |f: &mut std::fmt::Formatter<'_>, title: &str| -> std::fmt::Result {
+        writeln!(f, "| **{title}** | |")?;
+        if !has_header {
+            writeln!(f, "| --- |  ---  |")?;
+            has_header = true;
+        }
+        Ok(())
+    };
+
+    if let Some(node) = repr.as_node().filter(|_| from <= 2) {
         writeln!(f, "{node}")?;
     }
 
-    if let Some(resource) = repr.as_resource() {
+    if let Some(resource) = repr.as_resource().filter(|_| from == 0) {
         writeln!(f, "| **Resource Tags** | |")?;
         writeln!(f, "| --- |  ---  |")?;
         if let Some(name) = resource.type_name() {
@@ -319,9 +1174,9 @@ fn display_runmd(repr: &Repr, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Resu
         writeln!(f, "| uuid | {:?} |", resource.0.as_uuid())?;
     }
 
-    if let Some(field) = repr.as_field() {
+    if let Some(field) = repr.as_field().filter(|_| from <= 1) {
         if field.name().is_some() {
-            writeln!(f, "| **Field Tags** | |")?;
+            section(f, "Field Tags")?;
             if let Some(name) = field.name() {
                 writeln!(f, "| field_name | {name} |")?;
             }
@@ -341,9 +1196,9 @@ fn display_runmd(repr: &Repr, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Resu
         }
     }
 
-    if let Some(node) = repr.as_node() {
+    if let Some(node) = repr.as_node().filter(|_| from <= 2) {
         if let Some(path) = node.path() {
-            writeln!(f, "| **Node Tags** | |")?;
+            section(f, "Node Tags")?;
             writeln!(f, "| path | {path} |")?;
             writeln!(f, "| uuid | {:?} |", node.0.as_uuid())?;
             writeln!(f, "| span | {:?} |", node.span().unwrap_or_default())?;
@@ -355,15 +1210,15 @@ fn display_runmd(repr: &Repr, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Resu
         }
     }
 
-    if let Some(host) = repr.as_host() {
+    if let Some(host) = repr.as_host().filter(|_| from <= 3) {
         if let Some(addr) = host.address() {
-            writeln!(f, "| **Host Tags** | |")?;
+            section(f, "Host Tags")?;
             writeln!(f, "| addr | {addr} |")?;
             writeln!(f, "| uuid | {:?} |", host.0.as_uuid())?;
         }
     }
 
-    if let Some(recv) = repr.as_recv() {
+    if let Some(recv) = repr.as_recv().filter(|_| from <= 1) {
         writeln!(f)?;
         if let Some(fields) = recv.fields() {
             for _f in fields.iter() {
@@ -372,7 +1227,7 @@ fn display_runmd(repr: &Repr, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Resu
         }
     }
 
-    if let Some(host) = repr.as_host() {
+    if let Some(host) = repr.as_host().filter(|_| from <= 3) {
         writeln!(f)?;
         if let Some(ext) = host.extensions() {
             for e in ext.iter() {
@@ -390,15 +1245,15 @@ impl Display for NodeRepr {
             let mut docs = docs.iter();
 
             if let Some(header) = docs.next() {
-                writeln!(f, "# {}", header.trim_start_matches("# --").trim())?;
+                writeln!(f, "# {}", node::trim_doc_header(header))?;
             }
 
             for d in docs {
-                writeln!(f, "{}", d.trim_start_matches("# --").trim())?;
+                writeln!(f, "{}", node::trim_doc_header(d))?;
             }
         }
 
-        if let Some(source) = self.source() {
+        if let Some(source) = self.synthesize_source() {
             writeln!(f, "```runmd")?;
             for line in source.lines() {
                 if !line.starts_with('#') {
@@ -411,3 +1266,587 @@ impl Display for NodeRepr {
         Ok(())
     }
 }
+
+#[allow(unused)]
+mod tests {
+    use crate::prelude::*;
+
+    use crate::macros::test_field;
+
+    struct Test;
+
+    test_field!(Test, 0, "test");
+
+    #[test]
+    fn test_quick() {
+        struct Quick;
+
+        test_field!(Quick, 0, "quick", u64);
+
+        let repr = Repr::quick::<u64, 0, Quick>("7", "test://quick").unwrap();
+        assert_eq!(4, repr.get_levels().len());
+        assert_eq!(ReprKind::Host, repr.kind());
+
+        let resource = repr.as_resource().unwrap();
+        assert_eq!(Some("u64"), resource.type_name());
+        assert!(resource.is_parse_type::<u64>());
+
+        let field = repr.as_field().unwrap();
+        assert_eq!(Some("quick"), field.name());
+        assert_eq!(Some(0), field.offset());
+        assert_eq!(Some(std::any::type_name::<Quick>()), field.owner_name());
+
+        let node = repr.as_node().unwrap();
+        assert_eq!("7", node.input().unwrap().as_str());
+
+        let host = repr.as_host().unwrap();
+        assert_eq!("test://quick", host.address().unwrap().as_str());
+
+        assert_eq!(7, repr.parse_input::<u64>("7").unwrap());
+    }
+
+    #[test]
+    fn test_skeleton() {
+        let host = Repr::quick::<String, 0, Test>("skeleton", "test://skeleton").unwrap();
+        let skeleton = host.skeleton().unwrap();
+
+        let mut resource = ResourceLevel::new::<String>();
+        resource.set_parse_type::<String>();
+        resource.set_ffi::<String>();
+        let mut linker = Linker::<CrcInterner>::default();
+        linker.push_level(resource).unwrap();
+        linker.push_level(FieldLevel::new::<0, Test>()).unwrap();
+        let field = linker.link().unwrap();
+
+        assert_eq!(field, skeleton);
+        assert_eq!(ReprKind::Field, skeleton.kind());
+        assert!(skeleton.as_node().is_none());
+        assert_eq!(skeleton, skeleton.skeleton().unwrap());
+
+        let resource = Linker::new_crc::<Test>().link().unwrap();
+        assert!(resource.skeleton().is_err());
+    }
+
+    #[test]
+    fn test_level_path() {
+        let repr = Repr::quick::<String, 0, Test>("level path", "test://level_path").unwrap();
+        assert_eq!(Some(3), repr.level_index());
+        assert_eq!(
+            vec![LevelKind::Root, LevelKind::L1, LevelKind::L2, LevelKind::L3],
+            repr.level_path()
+        );
+
+        let resource = Linker::new_crc::<Test>().link().unwrap();
+        assert_eq!(Some(0), resource.level_index());
+        assert_eq!(vec![LevelKind::Root], resource.level_path());
+    }
+
+    #[test]
+    fn test_summary() {
+        let repr = Repr::quick::<String, 0, Test>("summary input", "test://summary").unwrap();
+        assert_eq!(
+            "alloc::string::String [test] summary input @test://summary",
+            repr.summary()
+        );
+
+        let long = "a".repeat(100);
+        let repr = Repr::quick::<String, 0, Test>(&long, "test://summary/long").unwrap();
+        assert_eq!(
+            format!(
+                "alloc::string::String [test] {}… @test://summary/long",
+                "a".repeat(48)
+            ),
+            repr.summary()
+        );
+        assert_eq!(
+            "alloc::string::String [test] aaaa… @test://summary/long",
+            repr.summary_truncated(4)
+        );
+
+        let multiline =
+            Repr::quick::<String, 0, Test>("first\nsecond", "test://summary/multiline").unwrap();
+        assert_eq!(
+            "alloc::string::String [test] first… @test://summary/multiline",
+            multiline.summary()
+        );
+
+        // Absent parts are omitted
+        let field = Test::linker::<CrcInterner>().unwrap().link().unwrap();
+        assert_eq!("alloc::string::String [test]", field.summary());
+    }
+
+    #[test]
+    fn test_description() {
+        let mut linker = Test::linker::<CrcInterner>().unwrap();
+        linker
+            .push_level(
+                NodeLevel::new()
+                    .with_input("description input")
+                    .with_doc_headers(vec!["# -- First line", "# --   Second line", "Third line"]),
+            )
+            .unwrap();
+        let repr = linker.link().unwrap();
+
+        assert_eq!(
+            Some("First line\nSecond line\nThird line".to_string()),
+            repr.description()
+        );
+        assert_eq!(Some("# -- First line".to_string()), repr.field_help());
+
+        let field = Test::linker::<CrcInterner>().unwrap().link().unwrap();
+        assert_eq!(None, field.description());
+    }
+
+    #[test]
+    fn test_value_parser_description() {
+        struct Described;
+
+        test_field!(Described, 0, "described", u32);
+
+        let field = Described::linker::<CrcInterner>().unwrap().link().unwrap();
+        assert_eq!(
+            Some("expected u32".to_string()),
+            field.value_parser_description()
+        );
+
+        struct NoFfi;
+        let resource = Linker::new_crc::<NoFfi>().link().unwrap();
+        assert_eq!(None, resource.value_parser_description());
+    }
+
+    #[test]
+    fn test_to_repr() {
+        struct ToRepr;
+
+        test_field!(ToRepr, 0, "to_repr");
+
+        impl Recv for ToRepr {
+            fn symbol() -> &'static str {
+                "to_repr"
+            }
+        }
+
+        let host = Repr::quick::<String, 0, ToRepr>("to repr", "test://to_repr").unwrap();
+        let levels = host.get_levels();
+
+        let resource = host.as_resource().unwrap().to_repr();
+        assert_eq!(levels[..1], resource.get_levels());
+        assert_eq!(
+            host.as_resource().unwrap().0,
+            resource.as_resource().unwrap().0
+        );
+
+        let field = host.as_field().unwrap().to_repr();
+        assert_eq!(levels[..2], field.get_levels());
+        assert_eq!(host.as_field().unwrap().0, field.as_field().unwrap().0);
+
+        let node = host.as_node().unwrap().to_repr();
+        assert_eq!(levels[..3], node.get_levels());
+        assert_eq!(host.as_node(), node.as_node());
+
+        assert_eq!(host, host.as_host().unwrap().to_repr());
+
+        let recv = ToRepr::link_recv(NodeLevel::new().with_symbol("to_repr"), vec![field]).unwrap();
+        let recv_repr = recv.as_recv().unwrap().to_repr();
+        assert_eq!(recv.get_levels()[..2], recv_repr.get_levels());
+        assert_eq!(recv.as_recv().unwrap().0, recv_repr.as_recv().unwrap().0);
+
+        let mut dependency = Linker::new_crc::<ToRepr>();
+        dependency
+            .push_level(DependencyLevel::new("to_repr"))
+            .unwrap();
+        let dependency = dependency.link().unwrap();
+        assert_eq!(dependency, dependency.as_dependency().unwrap().to_repr());
+
+        let mut variant = Linker::new_crc::<ToRepr>();
+        variant.push_level(VariantLevel::new("ToRepr", 0)).unwrap();
+        let variant = variant.link().unwrap();
+        assert_eq!(variant, variant.as_variant().unwrap().to_repr());
+    }
+
+    #[test]
+    #[allow(clippy::clone_on_copy)]
+    fn test_clone_shares_interned_values() {
+        use std::sync::Arc;
+
+        /// Asserts that a clone of a wrapper repr is only a copy of its handle, and resolves the same interned value,
+        ///
+        fn assert_shares<W, T>(wrapper: W, resolve: impl Fn(&W) -> Option<Arc<T>>)
+        where
+            W: Clone + PartialEq + std::fmt::Debug,
+        {
+            assert_eq!(
+                std::mem::size_of::<InternHandle>(),
+                std::mem::size_of::<W>()
+            );
+
+            let value = resolve(&wrapper).unwrap();
+            let cloned = wrapper.clone();
+            assert_eq!(wrapper, cloned);
+            assert!(Arc::ptr_eq(&value, &resolve(&cloned).unwrap()));
+        }
+
+        let field = Test::linker::<CrcInterner>().unwrap().link().unwrap();
+
+        assert_shares(field.as_resource().unwrap(), |r| {
+            crate::repr::resource::TYPE_NAME.strong_ref(&r.0)
+        });
+        assert_shares(field.as_field().unwrap(), |f| {
+            crate::repr::field::FIELD_NAME.strong_ref(&f.0)
+        });
+
+        let mut recv = Linker::new_crc::<Test>();
+        recv.push_level(RecvLevel::named("clone_shares", vec![field]))
+            .unwrap();
+        let recv = recv.link().unwrap().as_recv().unwrap();
+        assert_shares(recv, |r| r.name());
+        assert_shares(recv, |r| r.fields());
+
+        let mut dependency = Linker::new_crc::<Test>();
+        dependency
+            .push_level(DependencyLevel::new("clone_shares"))
+            .unwrap();
+        let dependency = dependency.link().unwrap().as_dependency().unwrap();
+        assert_shares(dependency, |d| d.name());
+
+        let mut variant = Linker::new_crc::<Test>();
+        variant
+            .push_level(VariantLevel::new("CloneShares", 0))
+            .unwrap();
+        let variant = variant.link().unwrap().as_variant().unwrap();
+        assert_shares(variant, |v| v.name());
+    }
+
+    #[test]
+    fn test_parse_with() {
+        struct ParseWith;
+
+        test_field!(ParseWith, 0, "parse_with", f64);
+
+        let repr = ParseWith::linker::<CrcInterner>().unwrap().link().unwrap();
+        let parsed = repr.parse_with::<f64>("3.14").unwrap();
+        assert_eq!("3.14", parsed.to_string());
+
+        let err = repr.parse_with::<f64>("nan-ish").unwrap_err();
+        assert!(err.contains("nan-ish"));
+
+        let err = repr.parse_with::<f32>("3.14").unwrap_err();
+        assert!(err.contains("f32"));
+    }
+
+    #[test]
+    fn test_parse_input() {
+        struct Count;
+
+        test_field!(Count, 0, "count", u32);
+
+        let repr = Count::linker::<CrcInterner>().unwrap().link().unwrap();
+        assert_eq!(42, repr.parse_input::<u32>("42").unwrap());
+        assert!(repr.parse_input::<u32>("forty-two").is_err());
+
+        let err = repr.parse_input::<u64>("42").unwrap_err();
+        assert!(err.to_string().contains("u64"));
+    }
+
+    #[test]
+    fn test_owner_repr() {
+        struct Owner;
+
+        test_field!(Owner, 0, "owned");
+
+        struct Undescribed;
+
+        test_field!(Undescribed, 0, "undescribed");
+
+        let owner = Linker::new_crc::<Owner>().link().unwrap();
+        let field = Owner::linker::<CrcInterner>().unwrap().link().unwrap();
+        assert_eq!(Some(owner), field.owner_repr());
+        assert!(owner.as_resource().unwrap().is_type::<Owner>());
+
+        let undescribed = Undescribed::linker::<CrcInterner>()
+            .unwrap()
+            .link()
+            .unwrap();
+        assert_eq!(None, undescribed.owner_repr());
+        assert_eq!(None, owner.owner_repr());
+
+        // Owners linked w/ a parse type or another interner are resolved
+        struct ParsedOwner;
+
+        test_field!(ParsedOwner, 0, "parsed");
+
+        let mut resource = ResourceLevel::new::<ParsedOwner>();
+        resource.set_parse_type::<String>();
+        let mut linker = Linker::<EntityInterner<CrcInterner>>::default();
+        linker.push_level(resource).unwrap();
+        let parsed = linker.link().unwrap();
+
+        let field = ParsedOwner::linker::<CrcInterner>()
+            .unwrap()
+            .link()
+            .unwrap();
+        assert_eq!(Some(parsed), field.owner_repr());
+
+        // Owners linked as the resource of a longer repr resolve to the resource level
+        struct Chained;
+
+        test_field!(Chained, 0, "chained");
+
+        let mut linker = Linker::<CrcInterner>::default();
+        linker.push_level(ResourceLevel::new::<Chained>()).unwrap();
+        linker.push_level(FieldLevel::new::<0, Chained>()).unwrap();
+        let chained = linker.link().unwrap();
+        assert_eq!(ReprKind::Field, chained.kind());
+
+        let field = Chained::linker::<CrcInterner>().unwrap().link().unwrap();
+        let owner = field.owner_repr().unwrap();
+        assert_eq!(ReprKind::Resource, owner.kind());
+        assert!(owner.as_resource().unwrap().is_type::<Chained>());
+    }
+
+    #[test]
+    fn test_all_reprs() {
+        struct All;
+
+        test_field!(All, 0, "all");
+
+        let prefix = All::linker::<CrcInterner>().unwrap().link().unwrap();
+
+        let reprs = ["one", "two", "three"].map(|input| {
+            let mut linker = All::linker::<CrcInterner>().unwrap();
+            linker
+                .push_level(NodeLevel::new().with_input(input))
+                .unwrap();
+            linker.link().unwrap()
+        });
+
+        // Reprs w/ the same content hash are distinct reprs
+        let symbols = ["all one", "all two"].map(|symbol| {
+            let mut linker = All::linker::<CrcInterner>().unwrap();
+            linker
+                .push_level(NodeLevel::new().with_input("one").with_symbol(symbol))
+                .unwrap();
+            linker.link().unwrap()
+        });
+        assert_eq!(symbols[0].content_hash(), symbols[1].content_hash());
+
+        let dependencies = ["all a", "all b"].map(|name| {
+            let mut linker = Linker::new_crc::<All>();
+            linker.push_level(DependencyLevel::new(name)).unwrap();
+            linker.link().unwrap()
+        });
+
+        let all = all_reprs();
+        for repr in reprs
+            .iter()
+            .chain(symbols.iter())
+            .chain(dependencies.iter())
+        {
+            assert!(all.contains(repr));
+        }
+        assert!(!all.contains(&prefix));
+    }
+
+    #[test]
+    fn test_uri() {
+        let repr = Test::linker::<CrcInterner>().unwrap().link().unwrap();
+
+        let uri = repr.to_uri();
+        assert_eq!(
+            format!("runir://alloc::string::String/test?id={}", repr.to_base58()),
+            uri
+        );
+        assert_eq!(repr, Repr::from_uri(&uri, &CrcInterner::default()).unwrap());
+
+        assert!(Repr::from_uri("http://example.com", &CrcInterner::default()).is_err());
+        assert!(Repr::from_uri("runir://_/_?id=not-base58!", &CrcInterner::default()).is_err());
+
+        // Reprs configured by another interner family are not resolved
+        let err = Repr::from_uri(&uri, &EntityInterner::<CrcInterner>::default()).unwrap_err();
+        assert!(err.to_string().contains("expected"));
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_uri_percent_encoded() {
+        struct Generic<T>(T);
+
+        let repr = Linker::new_crc::<Generic<(u8, String)>>().link().unwrap();
+
+        let uri = repr.to_uri();
+        assert_eq!(
+            format!(
+                "runir://{}/_?id={}",
+                std::any::type_name::<Generic<(u8, String)>>()
+                    .replace('<', "%3C")
+                    .replace('>', "%3E")
+                    .replace('(', "%28")
+                    .replace(')', "%29")
+                    .replace(',', "%2C")
+                    .replace(' ', "%20"),
+                repr.to_base58()
+            ),
+            uri
+        );
+        assert_eq!(repr, Repr::from_uri(&uri, &CrcInterner::default()).unwrap());
+        assert!(!logs_contain("does not match"));
+
+        assert!(Repr::from_uri("runir://%3/_?id=1", &CrcInterner::default()).is_err());
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_uri_mismatched_path_hint() {
+        let repr = Test::linker::<CrcInterner>().unwrap().link().unwrap();
+
+        let uri = format!("runir://wrong/hint?id={}", repr.to_base58());
+        assert_eq!(repr, Repr::from_uri(&uri, &CrcInterner::default()).unwrap());
+        assert!(logs_contain("Path hint wrong/hint does not match"));
+    }
+
+    #[test]
+    fn test_kind() {
+        struct Kind;
+
+        impl Recv for Kind {
+            fn symbol() -> &'static str {
+                "kind"
+            }
+        }
+
+        let resource = Linker::new_crc::<Kind>().link().unwrap();
+        assert_eq!(ReprKind::Resource, resource.kind());
+
+        let field = Test::linker::<CrcInterner>().unwrap().link().unwrap();
+        assert_eq!(ReprKind::Field, field.kind());
+
+        let mut recv = Linker::new_crc::<Kind>();
+        recv.push_level(RecvLevel::new::<Kind>(vec![field]))
+            .unwrap();
+        assert_eq!(ReprKind::Recv, recv.link().unwrap().kind());
+
+        let mut dependency = Linker::new_crc::<Kind>();
+        dependency.push_level(DependencyLevel::new("kind")).unwrap();
+        assert_eq!(ReprKind::Dependency, dependency.link().unwrap().kind());
+
+        let mut linker = Test::linker::<CrcInterner>().unwrap();
+        linker
+            .push_level(NodeLevel::new().with_input("kind"))
+            .unwrap();
+        assert_eq!(ReprKind::Node, linker.link().unwrap().kind());
+
+        linker.push_level(HostLevel::new("test://kind")).unwrap();
+        assert_eq!(ReprKind::Host, linker.link().unwrap().kind());
+
+        assert_eq!(ReprKind::Unknown, Repr::default().kind());
+    }
+
+    #[test]
+    fn test_push_node() {
+        let field = Test::linker::<CrcInterner>().unwrap().link().unwrap();
+
+        let mut interner = CrcInterner::default();
+        let node = field
+            .push_node(NodeLevel::new().with_input("push node"), &mut interner)
+            .unwrap();
+
+        assert_eq!(ReprKind::Field, field.kind());
+        assert!(field.as_node().is_none());
+
+        assert_eq!(ReprKind::Node, node.kind());
+        assert_eq!(
+            "push node",
+            node.as_node().unwrap().input().unwrap().as_str()
+        );
+        assert_eq!(Some(field), node.nearest(LevelFlags::LEVEL_1));
+
+        let resource = Linker::new_crc::<String>().link().unwrap();
+        resource
+            .push_node(NodeLevel::new().with_input("skipped field"), &mut interner)
+            .expect_err("should not be able to skip the field level");
+
+        // The node level is not configured when the ordering check fails
+        assert!(super::node::INPUT
+            .entries()
+            .iter()
+            .all(|(_, input)| input.as_str() != "skipped field"));
+    }
+
+    #[test]
+    fn test_nearest() {
+        let mut linker = Test::linker::<CrcInterner>().unwrap();
+        linker
+            .push_level(NodeLevel::new().with_input("nearest"))
+            .unwrap();
+        let node = linker.link().unwrap();
+
+        linker.push_level(HostLevel::new("test://nearest")).unwrap();
+        let host = linker.link().unwrap();
+
+        assert_eq!(Some(node), host.nearest(LevelFlags::LEVEL_2));
+        assert_eq!(Some(host), host.nearest(LevelFlags::LEVEL_3));
+        assert_eq!(
+            Some(LevelFlags::ROOT),
+            host.nearest(LevelFlags::ROOT).map(|r| r.tail.level_flags())
+        );
+        assert_eq!(None, node.nearest(LevelFlags::LEVEL_3));
+    }
+
+    #[test]
+    fn test_depends_on() {
+        struct Root;
+        struct Unrelated;
+
+        let root = Linker::new_crc::<Root>().link().unwrap();
+
+        let mut middle = Linker::new_crc::<String>();
+        middle
+            .push_level(DependencyLevel::new("middle").with_parent(root))
+            .unwrap();
+        let middle = middle.link().unwrap();
+
+        let mut leaf = Linker::new_crc::<String>();
+        leaf.push_level(DependencyLevel::new("leaf").with_parent(middle))
+            .unwrap();
+        let leaf = leaf.link().unwrap();
+
+        let unrelated = Linker::new_crc::<Unrelated>().link().unwrap();
+
+        assert_eq!(vec![middle, root], leaf.dependency_closure());
+        assert!(leaf.depends_on(&middle));
+        assert!(leaf.depends_on(&root));
+        assert!(middle.depends_on(&root));
+
+        assert!(!root.depends_on(&leaf));
+        assert!(!middle.depends_on(&leaf));
+        assert!(!leaf.depends_on(&unrelated));
+        assert!(!unrelated.depends_on(&root));
+    }
+
+    #[test]
+    fn test_render_delta() {
+        let mut linker = Test::linker::<CrcInterner>().unwrap();
+        let base = linker.link().unwrap();
+
+        linker
+            .push_level(
+                NodeLevel::new()
+                    .with_input("render delta")
+                    .with_path("delta/path"),
+            )
+            .unwrap();
+        let upgraded = linker.link().unwrap();
+        assert_eq!(2, upgraded.common_prefix_len(&base));
+
+        let delta = upgraded.render_delta(&base);
+        assert!(delta.contains("| **Node Tags** | |\n| --- |  ---  |"));
+        assert!(delta.contains("| path | delta/path |"));
+        assert!(!delta.contains("Resource Tags"));
+        assert!(!delta.contains("Field Tags"));
+
+        // The full render is unchanged
+        let full = format!("{:#}", upgraded);
+        assert!(full.contains("Resource Tags"));
+        assert!(full.contains("Field Tags"));
+    }
+}
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use crate::prelude::*;
+
+/// Trait for levels that can rebuild themselves w/ a namespace applied to their string tags,
+///
+pub trait Namespace {
+    /// Returns a copy of this level w/ the namespace prepended to string tags,
+    ///
+    fn namespaced(&self, namespace: &str) -> Self;
+}
+
+/// Wrapper level that applies a namespace prefix to the string tags of an inner level,
+///
+/// Tags are prefixed in the format `{namespace}::{value}`, which keeps levels from different
+/// namespaces from colliding in the shared intern tables.
+///
+pub struct Namespaced<L: Level + Namespace> {
+    /// Namespace to prepend,
+    ///
+    namespace: String,
+    /// Inner level,
+    ///
+    inner: L,
+}
+
+impl<L: Level + Namespace> Namespaced<L> {
+    /// Returns a new namespaced level,
+    ///
+    #[inline]
+    pub fn new(namespace: impl Into<String>, inner: L) -> Self {
+        Self {
+            namespace: namespace.into(),
+            inner,
+        }
+    }
+}
+
+impl<L: Level + Namespace> Level for Namespaced<L> {
+    fn configure(&self, interner: &mut impl InternerFactory) -> InternResult {
+        self.inner.namespaced(&self.namespace).configure(interner)
+    }
+
+    type Mount = L::Mount;
+
+    #[inline]
+    fn mount(&self) -> Self::Mount {
+        self.inner.namespaced(&self.namespace).mount()
+    }
+}
+
+/// Prepends a namespace to the value of a string tag,
+///
+pub(crate) fn namespace_tag(
+    namespace: &str,
+    tag: &Tag<String, Arc<String>>,
+) -> Tag<String, Arc<String>> {
+    Tag::new(
+        tag.intern_table,
+        Arc::new(format!("{namespace}::{}", tag.create_value)),
+    )
+}
+
+#[allow(unused)]
+mod tests {
+    use crate::prelude::*;
+
+    use crate::macros::test_field;
+
+    struct Test;
+
+    test_field!(Test, 0, "test");
+
+    #[cfg(feature = "crc-interner")]
+    fn tenant_repr(namespace: &str) -> Repr {
+        let node = NodeLevel::new()
+            .with_symbol("shared")
+            .with_path("shared/path");
+
+        let mut linker = Test::linker::<CrcInterner>().unwrap();
+        linker.push_level(Namespaced::new(namespace, node)).unwrap();
+        linker
+            .push_level(Namespaced::new(namespace, HostLevel::new("shared://")))
+            .unwrap();
+        linker.link().unwrap()
+    }
+
+    #[test]
+    fn test_namespaced() {
+        let a = tenant_repr("tenant_a");
+        let b = tenant_repr("tenant_b");
+        assert_ne!(a, b);
+        assert_ne!(a.as_node().unwrap(), b.as_node().unwrap());
+
+        let node = a.as_node().unwrap();
+        assert_eq!("tenant_a::shared", node.symbol().unwrap().as_str());
+        assert_eq!("tenant_a::shared/path", node.path().unwrap().as_str());
+        assert_eq!(
+            "tenant_b::shared://",
+            b.as_host().unwrap().address().unwrap().as_str()
+        );
+    }
+}
@@ -2,7 +2,12 @@ use std::collections::BTreeMap;
 use std::ops::Range;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::Weak;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
+use bytes::Bytes;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -10,6 +15,7 @@ use crate::define_intern_table;
 use crate::push_tag;
 
 use crate::prelude::*;
+use crate::repr::namespace::namespace_tag;
 
 // Intern table for symbol values
 define_intern_table!(SYMBOL: String);
@@ -44,6 +50,55 @@ define_intern_table!(SOURCE_SPAN: SourceSpan);
 // Intern table for node level source relative paths
 define_intern_table!(SOURCE_RELATIVE: PathBuf);
 
+// Intern table for node level timestamps
+define_intern_table!(TIMESTAMP: u64);
+
+// Intern table for node level binary payloads
+define_intern_table!(PAYLOAD: Bytes);
+
+// Hash of each shared node source, keyed by the address of the source
+static SOURCE_HASHES: Mutex<BTreeMap<usize, (Weak<String>, u64)>> = Mutex::new(BTreeMap::new());
+
+// Number of cached source hashes before entries of dropped sources are pruned
+const SOURCE_HASHES_PRUNE_THRESHOLD: usize = 256;
+
+/// Returns the hash of a node source,
+///
+fn hash_source(source: &str) -> u64 {
+    xxhash_rust::xxh3::xxh3_64(source.as_bytes())
+}
+
+/// Returns the hash of a shared node source, only hashing the source the first time it is seen while it is alive,
+///
+/// **Note** Entries hold a weak reference to the source, so an entry is only used while the source is alive and
+/// entries of dropped sources are removed when a new source is hashed and the cache is over
+/// `SOURCE_HASHES_PRUNE_THRESHOLD` entries.
+///
+fn shared_source_hash(source: &Arc<String>) -> u64 {
+    let key = Arc::as_ptr(source) as usize;
+    let cached = |hashes: &BTreeMap<usize, (Weak<String>, u64)>| {
+        hashes
+            .get(&key)
+            .filter(|(weak, _)| {
+                weak.strong_count() > 0 && std::ptr::eq(weak.as_ptr(), Arc::as_ptr(source))
+            })
+            .map(|(_, hash)| *hash)
+    };
+
+    if let Some(hash) = cached(&SOURCE_HASHES.lock().unwrap_or_else(|e| e.into_inner())) {
+        return hash;
+    }
+
+    let hash = hash_source(source);
+
+    let mut hashes = SOURCE_HASHES.lock().unwrap_or_else(|e| e.into_inner());
+    if hashes.len() >= SOURCE_HASHES_PRUNE_THRESHOLD {
+        hashes.retain(|_, (weak, _)| weak.strong_count() > 0);
+    }
+    hashes.insert(key, (Arc::downgrade(source), hash));
+    hash
+}
+
 /// Type-alias for start-and-end positions from the node's source,
 ///
 pub type SourceSpan = Range<usize>;
@@ -77,6 +132,11 @@ pub struct NodeLevel {
     /// Node source,
     ///
     source: Option<Tag<String, Arc<String>>>,
+    /// Precomputed hash of the node source,
+    ///
+    /// **Note** Computed when the source is set so that configuring this level does not hash the full source again.
+    ///
+    source_hash: Option<u64>,
     /// Node doc headers,
     ///
     doc_headers: Option<Tag<Vec<String>, Arc<Vec<String>>>>,
@@ -89,6 +149,12 @@ pub struct NodeLevel {
     /// Relative path name of the source for this node,
     ///
     relative: Option<Tag<PathBuf, Arc<PathBuf>>>,
+    /// Unix timestamp in milliseconds when this node was created,
+    ///
+    timestamp: Option<Tag<u64, Arc<u64>>>,
+    /// Binary payload attached to this node, i.e. a serialized default value,
+    ///
+    payload: Option<Tag<Bytes, Arc<Bytes>>>,
 }
 
 impl Default for NodeLevel {
@@ -109,10 +175,13 @@ impl NodeLevel {
             idx: None,
             bidx: None,
             source: None,
+            source_hash: None,
             doc_headers: None,
             annotations: None,
             span: None,
             relative: None,
+            timestamp: None,
+            payload: None,
         }
     }
 
@@ -220,6 +289,14 @@ impl NodeLevel {
         self
     }
 
+    /// Returns the node level w/ a shared source set, see `set_shared_source`,
+    ///
+    #[inline]
+    pub fn with_shared_source(mut self, source: Arc<String>) -> Self {
+        self.set_shared_source(source);
+        self
+    }
+
     /// Returns the node level w/ doc headers set,
     ///
     #[inline]
@@ -252,6 +329,41 @@ impl NodeLevel {
         self
     }
 
+    /// Returns the node level w/ timestamp set,
+    ///
+    /// **Note** Since the timestamp is part of the hash, this defeats content-addressing for this level.
+    ///
+    #[inline]
+    pub fn with_timestamp(mut self, ms: u64) -> Self {
+        self.set_timestamp(ms);
+        self
+    }
+
+    /// Returns the node level w/ timestamp set to the current system time,
+    ///
+    /// **Note** Since the timestamp is part of the hash, this defeats content-addressing for this level.
+    ///
+    #[inline]
+    pub fn with_now(mut self) -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+
+        self.set_timestamp(now);
+        self
+    }
+
+    /// Returns the node level w/ a binary payload set,
+    ///
+    /// **Note** The payload is part of the hash, so nodes w/ different payloads have different handles.
+    ///
+    #[inline]
+    pub fn with_payload(mut self, payload: impl Into<Bytes>) -> Self {
+        self.set_payload(payload);
+        self
+    }
+
     /// Sets the symbol tag for the node level,
     ///
     #[inline]
@@ -289,6 +401,10 @@ impl NodeLevel {
 
     /// Returns the node level w/ block idx tag set,
     ///
+    /// **Note** Block idx is not part of the identity of a node, so nodes that only differ by block idx share the same
+    /// handle. Configuring a node w/ a different block idx than the node that was first configured w/ that handle returns
+    /// an error.
+    ///
     #[inline]
     pub fn set_block(&mut self, idx: usize) {
         self.bidx = Some(Tag::new(&BLOCK_IDX, Arc::new(idx)));
@@ -298,7 +414,20 @@ impl NodeLevel {
     ///
     #[inline]
     pub fn set_source(&mut self, source: impl Into<String>) {
-        self.source = Some(Tag::new(&SOURCE, Arc::new(source.into())));
+        let source = source.into();
+        self.source_hash = Some(hash_source(&source));
+        self.source = Some(Tag::new(&SOURCE, Arc::new(source)));
+    }
+
+    /// Sets the source tag for the node level w/ a source shared by other node levels,
+    ///
+    /// The hash of a shared source is cached while the source is alive, so node levels parsed from the same source
+    /// (i.e. every node of a runmd file, or the nodes of a file that is re-parsed) only hash the source once.
+    ///
+    #[inline]
+    pub fn set_shared_source(&mut self, source: Arc<String>) {
+        self.source_hash = Some(shared_source_hash(&source));
+        self.source = Some(Tag::new(&SOURCE, source));
     }
 
     /// Sets the doc headers tag for the node level,
@@ -331,6 +460,29 @@ impl NodeLevel {
     pub fn set_source_relative(&mut self, relative: PathBuf) {
         self.relative = Some(Tag::new(&SOURCE_RELATIVE, Arc::new(relative)));
     }
+
+    /// Sets the node level timestamp in unix milliseconds,
+    ///
+    #[inline]
+    pub fn set_timestamp(&mut self, ms: u64) {
+        self.timestamp = Some(Tag::new(&TIMESTAMP, Arc::new(ms)));
+    }
+
+    /// Sets the binary payload of the node level,
+    ///
+    #[inline]
+    pub fn set_payload(&mut self, payload: impl Into<Bytes>) {
+        self.payload = Some(Tag::new(&PAYLOAD, Arc::new(payload.into())));
+    }
+}
+
+impl Namespace for NodeLevel {
+    fn namespaced(&self, namespace: &str) -> Self {
+        let mut node = self.clone();
+        node.symbol = self.symbol.as_ref().map(|s| namespace_tag(namespace, s));
+        node.path = self.path.as_ref().map(|p| namespace_tag(namespace, p));
+        node
+    }
 }
 
 impl Level for NodeLevel {
@@ -359,8 +511,9 @@ impl Level for NodeLevel {
             push_tag!(dyn interner, docs);
         }
 
-        if let Some(source) = self.source.as_ref() {
-            push_tag!(dyn interner, source);
+        if let (Some(source), Some(hash)) = (self.source.as_ref(), self.source_hash) {
+            let inner = source.clone();
+            interner.push_tag_prehashed(hash, move |h| inner.assign(h));
         }
 
         if let Some(annotations) = self.annotations.as_ref() {
@@ -375,6 +528,20 @@ impl Level for NodeLevel {
             push_tag!(dyn interner, source_relative);
         }
 
+        if let Some(timestamp) = self.timestamp.as_ref() {
+            push_tag!(dyn interner, timestamp);
+        }
+
+        if let Some(payload) = self.payload.as_ref() {
+            push_tag!(dyn interner, payload);
+        }
+
+        // Block idx is not part of the identity of a node, so a node w/ a different block idx than the node it shares a
+        // handle w/ returns an error
+        if let Some(idx) = self.bidx.as_ref().map(|b| *b.create_value) {
+            interner.push_assign(move |h| BLOCK_IDX.assign_unique(h, idx));
+        }
+
         interner.set_level_flags(LevelFlags::LEVEL_2);
 
         interner.interner()
@@ -408,12 +575,65 @@ impl Level for NodeLevel {
     }
 }
 
+/// Struct containing every tag of a node level,
+///
+/// **Note** Resolved w/ `NodeRepr::info`, which looks up each table once.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct NodeInfo {
+    /// Node symbol,
+    ///
+    pub symbol: Option<Arc<String>>,
+    /// Node input,
+    ///
+    pub input: Option<Arc<String>>,
+    /// Node tag,
+    ///
+    pub tag: Option<Arc<String>>,
+    /// Node path,
+    ///
+    pub path: Option<Arc<String>>,
+    /// Node idx,
+    ///
+    pub idx: Option<usize>,
+    /// Block idx,
+    ///
+    pub block_idx: Option<usize>,
+    /// Node source,
+    ///
+    pub source: Option<Arc<String>>,
+    /// Node doc headers,
+    ///
+    pub doc_headers: Option<Arc<Vec<String>>>,
+    /// Node annotations,
+    ///
+    pub annotations: Option<Arc<BTreeMap<String, String>>>,
+    /// Node source span,
+    ///
+    pub span: Option<Arc<SourceSpan>>,
+    /// Node source relative path,
+    ///
+    pub relative: Option<Arc<PathBuf>>,
+    /// Node binary payload,
+    ///
+    pub payload: Option<Arc<Bytes>>,
+}
+
 /// Wrapper struct with access to node tags,
 ///
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Serialize, Deserialize)]
 pub struct NodeRepr(pub(crate) InternHandle);
 
 impl NodeRepr {
+    /// Returns the repr whose tail is this level,
+    ///
+    /// **Note** If this level has not been linked, the returned repr only contains this level.
+    ///
+    #[inline]
+    pub fn to_repr(&self) -> Repr {
+        Repr::from_level(self.0)
+    }
+
     /// Returns the node symbol,
     ///
     pub fn symbol(&self) -> Option<Arc<String>> {
@@ -448,6 +668,16 @@ impl NodeRepr {
         self.0.node_idx()
     }
 
+    /// Returns the block idx,
+    ///
+    /// **Note** If nodes that only differ by block idx were configured, this is the block idx of the first node, see
+    /// `NodeLevel::set_block`.
+    ///
+    #[inline]
+    pub fn block_idx(&self) -> Option<usize> {
+        BLOCK_IDX.copy(&self.0)
+    }
+
     /// Returns the node source,
     ///
     #[inline]
@@ -455,6 +685,86 @@ impl NodeRepr {
         self.0.node_source()
     }
 
+    /// Returns the node source, or synthesizes a runmd source from the doc headers and input,
+    ///
+    /// When the source was not interned, each doc header is written as a `# --` comment followed by
+    /// the input line. Returns None if there is no source, doc headers, or input.
+    ///
+    pub fn synthesize_source(&self) -> Option<String> {
+        if let Some(source) = self.source() {
+            return Some(source.to_string());
+        }
+
+        let docs = self.doc_headers();
+        let input = self.input();
+        if docs.is_none() && input.is_none() {
+            return None;
+        }
+
+        let mut source = String::new();
+        for header in docs.iter().flat_map(|d| d.iter()) {
+            source.push_str("# -- ");
+            source.push_str(trim_doc_header(header));
+            source.push('\n');
+        }
+
+        if let Some(input) = input {
+            source.push_str(input.as_str());
+            source.push('\n');
+        }
+
+        Some(source)
+    }
+
+    /// Returns a node level w/ the tags of this node,
+    ///
+    /// **Note** Configuring the returned level produces an equivalent node level, which allows this level to be pushed
+    /// onto another linker.
+    ///
+    pub fn to_level(&self) -> NodeLevel {
+        let mut level = NodeLevel::new();
+        if let Some(symbol) = self.symbol() {
+            level.set_symbol(symbol.as_str());
+        }
+        if let Some(input) = self.input() {
+            level.set_input(input.as_str());
+        }
+        if let Some(tag) = self.tag() {
+            level.set_tag(tag.as_str());
+        }
+        if let Some(path) = self.path() {
+            level.set_path(path.as_str());
+        }
+        if let Some(idx) = self.idx() {
+            level.set_idx(idx);
+        }
+        if let Some(bidx) = BLOCK_IDX.copy(&self.0) {
+            level.set_block(bidx);
+        }
+        if let Some(source) = self.source() {
+            level.set_shared_source(source);
+        }
+        if let Some(doc_headers) = self.doc_headers() {
+            level.set_doc_headers(doc_headers.to_vec());
+        }
+        if let Some(annotations) = self.annotations() {
+            level.set_annotations(annotations.as_ref().clone());
+        }
+        if let Some(span) = self.span() {
+            level.set_source_span(span.as_ref().clone());
+        }
+        if let Some(relative) = self.relative() {
+            level.set_source_relative(relative.to_path_buf());
+        }
+        if let Some(timestamp) = self.timestamp() {
+            level.set_timestamp(timestamp);
+        }
+        if let Some(payload) = self.payload() {
+            level.set_payload(payload);
+        }
+        level
+    }
+
     /// Returns node doc_headers,
     ///
     #[inline]
@@ -476,10 +786,311 @@ impl NodeRepr {
         self.0.source_span()
     }
 
+    /// Returns the text of the node source within the node source span,
+    ///
+    /// **Note** Returns None if the span is out of range of the source, or does not fall on a char boundary, which can
+    /// happen if the source was edited after the span was recorded.
+    ///
+    pub fn span_text(&self) -> Option<String> {
+        let source = self.source()?;
+        let span = self.span()?;
+
+        source.get(span.start..span.end).map(str::to_string)
+    }
+
     /// Returns node source relative path,
     ///
     #[inline]
     pub fn relative(&self) -> Option<Arc<PathBuf>> {
         self.0.source_relative()
     }
+
+    /// Returns the node timestamp in unix milliseconds,
+    ///
+    #[inline]
+    pub fn timestamp(&self) -> Option<u64> {
+        self.0.timestamp()
+    }
+
+    /// Returns the node binary payload,
+    ///
+    #[inline]
+    pub fn payload(&self) -> Option<Bytes> {
+        self.0.payload()
+    }
+
+    /// Returns every tag of this node level,
+    ///
+    /// **Note** Each table is looked up once w/ the node level handle, rather than resolving the level from the tail
+    /// of the repr for each accessor.
+    ///
+    pub fn info(&self) -> NodeInfo {
+        let handle = self.0;
+
+        NodeInfo {
+            symbol: SYMBOL.strong_ref(&handle),
+            input: INPUT.strong_ref(&handle),
+            tag: TAG.strong_ref(&handle),
+            path: PATH.strong_ref(&handle),
+            idx: NODE_IDX.copy(&handle),
+            block_idx: BLOCK_IDX.copy(&handle),
+            source: SOURCE.strong_ref(&handle),
+            doc_headers: DOC_HEADERS.strong_ref(&handle),
+            annotations: ANNOTATIONS.strong_ref(&handle),
+            span: SOURCE_SPAN.strong_ref(&handle),
+            relative: SOURCE_RELATIVE.strong_ref(&handle),
+            payload: PAYLOAD.strong_ref(&handle),
+        }
+    }
+}
+
+/// Returns a doc header w/o the leading `# --` comment prefix and surrounding whitespace,
+///
+pub(crate) fn trim_doc_header(header: &str) -> &str {
+    header.trim_start_matches("# --").trim()
+}
+
+#[allow(unused)]
+mod tests {
+    use crate::prelude::*;
+
+    use crate::macros::test_field;
+
+    struct Test;
+
+    test_field!(Test, 0, "test");
+
+    #[test]
+    fn test_node_timestamp() {
+        let mut linker = Test::linker::<CrcInterner>().unwrap();
+        linker
+            .push_level(
+                NodeLevel::new()
+                    .with_input("hello world")
+                    .with_timestamp(1234),
+            )
+            .unwrap();
+        let repr = linker.link().unwrap();
+
+        assert_eq!(Some(1234), repr.as_node().unwrap().timestamp());
+
+        let mut linker = Test::linker::<CrcInterner>().unwrap();
+        linker
+            .push_level(
+                NodeLevel::new()
+                    .with_input("hello world")
+                    .with_timestamp(5678),
+            )
+            .unwrap();
+        let other = linker.link().unwrap();
+
+        assert_eq!(Some(5678), other.as_node().unwrap().timestamp());
+        assert_ne!(repr, other);
+    }
+
+    #[test]
+    fn test_synthesize_source() {
+        let mut linker = Test::linker::<CrcInterner>().unwrap();
+        linker
+            .push_level(
+                NodeLevel::new()
+                    .with_symbol("synthesize")
+                    .with_input("hello world")
+                    .with_doc_headers(vec!["# -- Example header", "Second line"]),
+            )
+            .unwrap();
+        let node = linker.link().unwrap().as_node().unwrap();
+
+        assert!(node.source().is_none());
+        assert_eq!(
+            "# -- Example header\n# -- Second line\nhello world\n",
+            node.synthesize_source().unwrap()
+        );
+        assert!(node.to_string().ends_with("```runmd\nhello world\n```\n"));
+    }
+
+    #[test]
+    fn test_span_text() {
+        let node = |span: super::SourceSpan| {
+            let mut linker = Test::linker::<CrcInterner>().unwrap();
+            linker
+                .push_level(
+                    NodeLevel::new()
+                        .with_symbol("span_text")
+                        .with_input(format!("{span:?}"))
+                        .with_source("```runmd\n+ .operation span\n```")
+                        .with_source_span(span),
+                )
+                .unwrap();
+            linker.link().unwrap().as_node().unwrap()
+        };
+
+        assert_eq!(
+            "+ .operation span",
+            node(9..26).span_text().unwrap().as_str()
+        );
+        assert!(node(9..100).span_text().is_none());
+    }
+
+    #[test]
+    fn test_source_prehashed() {
+        let source = "+ .operation prehashed\n".repeat(64 * 1024);
+        let node = NodeLevel::new()
+            .with_symbol("prehashed")
+            .with_source(source.as_str());
+
+        // Configuring the same level again only hashes the precomputed source hash
+        let mut interner = CrcInterner::default();
+        let first = node.configure(&mut interner).unwrap();
+        let second = node.configure(&mut interner).unwrap();
+        assert_eq!(first, second);
+
+        // A level w/ an equal source produces the same handle
+        let reparsed = NodeLevel::new()
+            .with_symbol("prehashed")
+            .with_source(source.clone())
+            .configure(&mut interner)
+            .unwrap();
+        assert_eq!(first, reparsed);
+        assert_eq!(source.as_str(), first.node_source().unwrap().as_str());
+
+        let other = NodeLevel::new()
+            .with_symbol("prehashed")
+            .with_source("+ .operation other")
+            .configure(&mut interner)
+            .unwrap();
+        assert_ne!(first, other);
+
+        // Interning a shared source caches the hash of the source while the source is alive
+        let shared = std::sync::Arc::new(source.clone());
+        let cached = |source: &std::sync::Arc<String>| {
+            super::SOURCE_HASHES
+                .lock()
+                .unwrap()
+                .get(&(std::sync::Arc::as_ptr(source) as usize))
+                .map(|(_, hash)| *hash)
+        };
+
+        let first_shared = NodeLevel::new()
+            .with_symbol("prehashed")
+            .with_shared_source(shared.clone())
+            .configure(&mut interner)
+            .unwrap();
+        assert_eq!(Some(super::hash_source(&shared)), cached(&shared));
+
+        let second_shared = NodeLevel::new()
+            .with_symbol("prehashed")
+            .with_shared_source(shared.clone())
+            .configure(&mut interner)
+            .unwrap();
+        assert_eq!(first, first_shared);
+        assert_eq!(first, second_shared);
+
+        // Converting the node back into a level shares the interned source
+        let interned = first.node_source().unwrap();
+        let to_level = NodeRepr(first).to_level().configure(&mut interner).unwrap();
+        assert_eq!(Some(super::hash_source(&interned)), cached(&interned));
+        assert_eq!(first, to_level);
+    }
+
+    #[test]
+    fn test_node_info() {
+        let mut annotations = std::collections::BTreeMap::new();
+        annotations.insert("info".to_string(), "annotation".to_string());
+
+        let mut linker = Test::linker::<CrcInterner>().unwrap();
+        linker
+            .push_level(
+                NodeLevel::new()
+                    .with_symbol("info")
+                    .with_input("node info")
+                    .with_tag("info-tag")
+                    .with_path("info/path")
+                    .with_idx(4)
+                    .with_block(7)
+                    .with_source("+ .info node info")
+                    .with_doc_headers(vec!["# -- Info header"])
+                    .with_annotations(annotations.clone())
+                    .with_source_span(8..17)
+                    .with_source_relative("info.md".into()),
+            )
+            .unwrap();
+        let node = linker.link().unwrap().as_node().unwrap();
+
+        let info = node.info();
+        assert_eq!("info", info.symbol.unwrap().as_str());
+        assert_eq!("node info", info.input.unwrap().as_str());
+        assert_eq!("info-tag", info.tag.unwrap().as_str());
+        assert_eq!("info/path", info.path.unwrap().as_str());
+        assert_eq!(Some(4), info.idx);
+        assert_eq!(Some(7), info.block_idx);
+        assert_eq!("+ .info node info", info.source.unwrap().as_str());
+        assert_eq!(
+            vec!["# -- Info header".to_string()],
+            *info.doc_headers.unwrap()
+        );
+        assert_eq!(annotations, *info.annotations.unwrap());
+        assert_eq!(8..17, *info.span.unwrap());
+        assert_eq!(std::path::PathBuf::from("info.md"), *info.relative.unwrap());
+
+        // Block idx is not part of the node level identity
+        let mut linker = Test::linker::<CrcInterner>().unwrap();
+        linker
+            .push_level(NodeLevel::new().with_input("node info block"))
+            .unwrap();
+        let a = linker.link().unwrap();
+
+        let mut linker = Test::linker::<CrcInterner>().unwrap();
+        linker
+            .push_level(NodeLevel::new().with_input("node info block").with_block(2))
+            .unwrap();
+        let b = linker.link().unwrap();
+        assert_eq!(a, b);
+        assert_eq!(Some(2), b.as_node().unwrap().block_idx());
+
+        // A node that only differs by block idx cannot be configured w/ the same handle
+        let mut linker = Test::linker::<CrcInterner>().unwrap();
+        let err = linker
+            .push_level(NodeLevel::new().with_input("node info block").with_block(5))
+            .unwrap_err();
+        assert!(err.to_string().contains("already assigned 2"));
+        assert_eq!(Some(2), b.as_node().unwrap().block_idx());
+
+        // Configuring the same block idx again is allowed
+        let mut linker = Test::linker::<CrcInterner>().unwrap();
+        linker
+            .push_level(NodeLevel::new().with_input("node info block").with_block(2))
+            .unwrap();
+        assert_eq!(b, linker.link().unwrap());
+    }
+
+    #[test]
+    fn test_node_payload() {
+        let link = |payload: &'static [u8]| {
+            let mut linker = Test::linker::<CrcInterner>().unwrap();
+            linker
+                .push_level(
+                    NodeLevel::new()
+                        .with_input("node payload")
+                        .with_payload(payload),
+                )
+                .unwrap();
+            linker.link().unwrap()
+        };
+
+        let a = link(b"\x00payload a");
+        let b = link(b"\x00payload b");
+        assert_ne!(a, b);
+
+        let node = a.as_node().unwrap();
+        assert_eq!(
+            Some(bytes::Bytes::from_static(b"\x00payload a")),
+            node.payload()
+        );
+        assert_eq!(node.payload(), node.info().payload.map(|p| (*p).clone()));
+        assert_eq!(
+            Some(bytes::Bytes::from_static(b"\x00payload b")),
+            b.as_node().unwrap().payload()
+        );
+    }
 }
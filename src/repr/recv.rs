@@ -1,5 +1,10 @@
+use std::hash::Hasher;
 use std::sync::Arc;
 
+use serde::Deserialize;
+use serde::Serialize;
+use xxhash_rust::xxh3::Xxh3;
+
 use crate::define_intern_table;
 use crate::prelude::*;
 use crate::push_tag;
@@ -10,6 +15,9 @@ define_intern_table!(RECV_NAMES: String);
 // Intern table containing repr's of receiver owned fields
 define_intern_table!(RECV_FIELDS: Vec<Repr>);
 
+// Intern table of receivers w/ content-addressed fields
+define_intern_table!(RECV_CONTENT_ADDRESSED: bool);
+
 /// Allows types to link runmd nodes to their respective type/field representation levels,
 ///
 pub trait Recv {
@@ -17,6 +25,33 @@ pub trait Recv {
     ///
     fn symbol() -> &'static str;
 
+    /// Returns the reprs of the fields owned by this receiver,
+    ///
+    /// **Note** By default a receiver has no fields, implementations should return the repr of each
+    /// `Field<N>` implemented by the receiver, i.e. w/ `field_reprs!(Self: 0, 1, 2)`.
+    ///
+    fn fields() -> anyhow::Result<Vec<Repr>>
+    where
+        Self: Sized,
+    {
+        Ok(vec![])
+    }
+
+    /// Describes the receiver and all of its fields, returning a recv level repr,
+    ///
+    /// **Note** The node level is tagged w/ the receiver symbol, since an empty node level would
+    /// resolve to the same handle for every receiver.
+    ///
+    fn describe_recv() -> anyhow::Result<Repr>
+    where
+        Self: Sized + Send + Sync + 'static,
+    {
+        Self::link_recv(
+            NodeLevel::new().with_symbol(Self::symbol()),
+            Self::fields()?,
+        )
+    }
+
     /// Links a node level to a receiver and returns a new Repr,
     ///
     fn link_recv(node: NodeLevel, fields: Vec<Repr>) -> anyhow::Result<Repr>
@@ -53,7 +88,12 @@ pub struct RecvLevel {
     name: Tag<String, Arc<String>>,
     /// **Active** fields owned by this receiver,
     ///
+    /// **Note** A field can also be the recv level repr of a nested receiver, see `RecvRepr::nested_recvs`.
+    ///
     fields: Tag<Vec<Repr>, Arc<Vec<Repr>>>,
+    /// True if the fields are interned by content rather than by handle,
+    ///
+    content_addressed: bool,
 }
 
 impl RecvLevel {
@@ -66,14 +106,58 @@ impl RecvLevel {
         Self {
             name: Tag::new(&RECV_NAMES, Arc::new(R::symbol().to_string())),
             fields: Tag::new(&RECV_FIELDS, Arc::new(fields)),
+            content_addressed: false,
+        }
+    }
+
+    /// Creates a new receiver level w/ a receiver name,
+    ///
+    pub(crate) fn named(name: impl Into<String>, fields: Vec<Repr>) -> Self {
+        Self {
+            name: Tag::new(&RECV_NAMES, Arc::new(name.into())),
+            fields: Tag::new(&RECV_FIELDS, Arc::new(fields)),
+            content_addressed: false,
         }
     }
+
+    /// Interns the fields of this receiver by the content hash of each field rather than by handle,
+    ///
+    /// Field handles include the entropy they were created with, so receivers w/ the same fields created under
+    /// different entropy will only share a canonical handle when the fields are content-addressed.
+    ///
+    /// **Note** The fingerprint of each field is resolved when this level is configured, so each field must be
+    /// resolvable w/ the entropy of the current thread. Receivers w/ the same name and field fingerprints share a
+    /// handle, so the fields of the first receiver configured w/ that handle are the fields that are resolved.
+    ///
+    #[inline]
+    pub fn content_addressed(mut self) -> Self {
+        self.content_addressed = true;
+        self
+    }
+
+    /// Returns a hash of the fingerprint of each field, in order,
+    ///
+    fn fields_content_hash(&self) -> u64 {
+        let mut hasher = Xxh3::new();
+        for field in self.fields.create_value.iter() {
+            hasher.write_u128(field.fingerprint());
+        }
+        hasher.finish()
+    }
 }
 
 impl Level for RecvLevel {
     fn configure(&self, interner: &mut impl InternerFactory) -> InternResult {
         push_tag!(dyn interner, &self.name);
-        push_tag!(dyn interner, &self.fields);
+        if self.content_addressed {
+            let fields = self.fields.clone();
+            interner.push_tag_prehashed(self.fields_content_hash(), move |h| fields.assign(h));
+
+            // The prehashed fields already distinguish content-addressed receivers
+            interner.push_assign(|h| RECV_CONTENT_ADDRESSED.assign_unique(h, true));
+        } else {
+            push_tag!(dyn interner, &self.fields);
+        }
 
         interner.set_level_flags(LevelFlags::LEVEL_1);
         interner.interner()
@@ -86,9 +170,38 @@ impl Level for RecvLevel {
 
 /// Wrapper-struct for an intern handle providing api's to access receiver level tags,
 ///
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Serialize, Deserialize)]
 pub struct RecvRepr(pub(crate) InternHandle);
 
 impl RecvRepr {
+    /// Returns the repr whose tail is this level,
+    ///
+    /// **Note** If this level has not been linked, the returned repr only contains this level.
+    ///
+    #[inline]
+    pub fn to_repr(&self) -> Repr {
+        Repr::from_level(self.0)
+    }
+
+    /// Returns a receiver level w/ the name and fields of this receiver,
+    ///
+    /// **Note** Returns None if the name or fields of this receiver could not be resolved. The returned level is
+    /// content-addressed if this receiver is content-addressed.
+    ///
+    pub fn to_level(&self) -> Option<RecvLevel> {
+        let level = RecvLevel {
+            name: Tag::new(&RECV_NAMES, self.name()?),
+            fields: Tag::new(&RECV_FIELDS, self.fields()?),
+            content_addressed: false,
+        };
+
+        if self.is_content_addressed() {
+            Some(level.content_addressed())
+        } else {
+            Some(level)
+        }
+    }
+
     /// Returns the name of the receiver,
     ///
     #[inline]
@@ -103,6 +216,184 @@ impl RecvRepr {
         self.0.recv_fields()
     }
 
+    /// Returns true if the fields of this receiver are content-addressed, see `RecvLevel::content_addressed`,
+    ///
+    #[inline]
+    pub fn is_content_addressed(&self) -> bool {
+        RECV_CONTENT_ADDRESSED.copy(&self.0).unwrap_or_default()
+    }
+
+    /// Returns the receiver fields as field reprs,
+    ///
+    /// **Note** Skips any entries that are not field level representations.
+    ///
+    pub fn field_reprs(&self) -> Vec<FieldRepr> {
+        self.fields()
+            .map(|fields| {
+                fields
+                    .iter()
+                    .filter_map(Repr::as_field)
+                    .filter(|f| f.name().is_some())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the receivers nested in the fields of this receiver,
+    ///
+    /// **Note** Skips any entries that are not recv level representations.
+    ///
+    pub fn nested_recvs(&self) -> Vec<RecvRepr> {
+        self.fields()
+            .map(|fields| {
+                fields
+                    .iter()
+                    .filter_map(Repr::as_recv)
+                    .filter(|r| r.name().is_some())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Finds the field repr of a field owned by receiver,
+    ///
+    pub fn field(&self, name: &str) -> Option<FieldRepr> {
+        self.field_reprs()
+            .into_iter()
+            .find(|f| f.name() == Some(name))
+    }
+
+    /// Returns the difference between the fields of this receiver and the fields of other,
+    ///
+    /// Fields are compared by name, `added` contains the fields only owned by other, `removed` contains the fields only
+    /// owned by this receiver, and `changed` contains the fields owned by both w/ a different offset, owner size or type.
+    ///
+    pub fn field_diff(&self, other: &RecvRepr) -> FieldDiff {
+        let fields = self.field_reprs();
+        let other_fields = other.field_reprs();
+
+        let mut diff = FieldDiff::default();
+        for field in fields.iter() {
+            let name = field.name().unwrap_or_default();
+            match other_fields.iter().find(|f| f.name() == field.name()) {
+                Some(other_field) => {
+                    let type_name =
+                        |f: &FieldRepr| f.to_repr().as_resource().and_then(|r| r.type_name());
+
+                    if field.offset() != other_field.offset()
+                        || field.owner_size() != other_field.owner_size()
+                        || type_name(field) != type_name(other_field)
+                    {
+                        diff.changed.push(name);
+                    }
+                }
+                None => diff.removed.push(name),
+            }
+        }
+
+        for other_field in other_fields.iter() {
+            if !fields.iter().any(|f| f.name() == other_field.name()) {
+                diff.added.push(other_field.name().unwrap_or_default());
+            }
+        }
+
+        diff
+    }
+
+    /// Returns a clap command w/ an argument for each field owned by this receiver,
+    ///
+    /// **Note** Fields that cannot be expressed as a cli argument are skipped.
+    ///
+    #[cfg(feature = "util-clap")]
+    pub fn to_command(&self) -> clap::Command {
+        let name = self.name().map(|n| n.to_string()).unwrap_or_default();
+
+        let mut command = clap::Command::new(name);
+        for field in self.fields().iter().flat_map(|f| f.iter()) {
+            if let Some((name, help, ffi_type, value_parser)) = field.split_for_arg() {
+                let mut arg = clap::Arg::new(name).long(name).value_parser(value_parser);
+
+                if ffi_type == <() as FFI>::ffi_type_name() {
+                    arg = arg.action(clap::ArgAction::SetTrue);
+                }
+
+                if let Some(help) = help {
+                    arg = arg.help(help);
+                }
+
+                command = command.arg(arg);
+            }
+        }
+        command
+    }
+
+    /// Returns a JSON Schema object describing the fields owned by this receiver,
+    ///
+    /// Each field is a property keyed by the field name, typed by the field's ffi type and described by the field help.
+    ///
+    /// **Note** Fields w/o a field name are skipped, and fields w/ an ffi type that does not map to a JSON Schema type
+    /// are left untyped.
+    ///
+    #[cfg(feature = "json")]
+    pub fn to_json_schema(&self) -> serde_json::Value {
+        let mut properties = serde_json::Map::new();
+
+        for field in self.fields().iter().flat_map(|f| f.iter()) {
+            let Some(name) = field.field_name() else {
+                continue;
+            };
+
+            let mut property = serde_json::Map::new();
+            if let Some(ty) = field.ffi_info().and_then(|i| i.json_schema_type) {
+                property.insert("type".to_string(), ty.into());
+            }
+            if let Some(help) = field.field_help() {
+                property.insert("description".to_string(), help.into());
+            }
+
+            properties.insert(name.to_string(), property.into());
+        }
+
+        serde_json::json!({
+            "type": "object",
+            "properties": properties,
+        })
+    }
+
+    /// Converts matches parsed w/ the command from `to_command` into node level reprs,
+    ///
+    /// Each field that has a matched value is upgraded to a node level w/ the raw matched string as input.
+    ///
+    #[cfg(feature = "util-clap")]
+    pub fn matches_to_reprs(
+        &self,
+        matches: &clap::ArgMatches,
+        interner: &mut impl InternerFactory,
+    ) -> anyhow::Result<Vec<Repr>> {
+        let mut reprs = vec![];
+
+        for field in self.fields().iter().flat_map(|f| f.iter()) {
+            let Some(name) = field.field_name() else {
+                continue;
+            };
+
+            let input = matches
+                .try_get_raw(name)
+                .ok()
+                .flatten()
+                .and_then(|mut raw| raw.next())
+                .map(|raw| raw.to_string_lossy().to_string());
+
+            if let Some(input) = input {
+                let mut repr = *field;
+                repr.upgrade(&mut *interner, NodeLevel::new().with_input(input))?;
+                reprs.push(repr);
+            }
+        }
+
+        Ok(reprs)
+    }
+
     /// Finds the repr of a field owned by receiver,
     ///
     pub fn find_field(&self, name: &str) -> Option<Repr> {
@@ -126,3 +417,394 @@ impl RecvRepr {
         }
     }
 }
+
+/// Struct containing the names of fields that differ between two receivers,
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// Fields that were added,
+    ///
+    pub added: Vec<&'static str>,
+    /// Fields that were removed,
+    ///
+    pub removed: Vec<&'static str>,
+    /// Fields w/ the same name, but a different offset, owner size or type,
+    ///
+    pub changed: Vec<&'static str>,
+}
+
+#[allow(unused)]
+mod tests {
+    use crate::prelude::*;
+
+    use crate::macros::test_field;
+
+    use super::RECV_FIELDS;
+
+    struct Test;
+
+    impl Recv for Test {
+        fn symbol() -> &'static str {
+            "test"
+        }
+    }
+
+    test_field!(Test, 0, "name");
+    test_field!(Test, 1, "count", u32);
+
+    #[test]
+    fn test_recv_field_reprs() {
+        let name = <Test as Field<0>>::linker::<CrcInterner>()
+            .unwrap()
+            .link()
+            .unwrap();
+        let count = <Test as Field<1>>::linker::<CrcInterner>()
+            .unwrap()
+            .link()
+            .unwrap();
+
+        let repr = Test::link_recv(NodeLevel::new(), vec![name, count]).unwrap();
+        let recv = repr.as_recv().unwrap();
+
+        let fields = recv.field_reprs();
+        assert_eq!(2, fields.len());
+        assert_eq!(Some("name"), fields[0].name());
+        assert_eq!(Some("count"), fields[1].name());
+
+        let count = recv.field("count").unwrap();
+        assert_eq!(Some(1), count.offset());
+        assert!(recv.field("missing").is_none());
+    }
+
+    #[test]
+    fn test_recv_nested_recvs() {
+        struct Inner;
+
+        impl Recv for Inner {
+            fn symbol() -> &'static str {
+                "inner"
+            }
+
+            fn fields() -> anyhow::Result<Vec<Repr>> {
+                Ok(vec![])
+            }
+        }
+
+        struct Outer;
+
+        impl Recv for Outer {
+            fn symbol() -> &'static str {
+                "outer"
+            }
+        }
+
+        let name = <Test as Field<0>>::linker::<CrcInterner>()
+            .unwrap()
+            .link()
+            .unwrap();
+        let inner = Inner::describe_recv().unwrap();
+
+        let outer = Outer::link_recv(
+            NodeLevel::new().with_symbol(Outer::symbol()),
+            vec![name, inner],
+        )
+        .unwrap();
+        let recv = outer.as_recv().unwrap();
+
+        let nested = recv.nested_recvs();
+        assert_eq!(1, nested.len());
+        assert_eq!("inner", nested[0].name().unwrap().as_str());
+        assert_eq!(inner.as_recv().unwrap().0, nested[0].0);
+
+        // Nested receivers are not field reprs
+        let fields = recv.field_reprs();
+        assert_eq!(1, fields.len());
+        assert_eq!(Some("name"), fields[0].name());
+
+        assert!(inner.as_recv().unwrap().nested_recvs().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "util-clap")]
+    fn test_recv_matches_to_reprs() {
+        let name = <Test as Field<0>>::linker::<CrcInterner>()
+            .unwrap()
+            .link()
+            .unwrap();
+        let count = <Test as Field<1>>::linker::<CrcInterner>()
+            .unwrap()
+            .link()
+            .unwrap();
+
+        let repr = Test::link_recv(NodeLevel::new(), vec![name, count]).unwrap();
+        let recv = repr.as_recv().unwrap();
+
+        let matches = recv
+            .to_command()
+            .try_get_matches_from(["test", "--name", "hello", "--count", "5"])
+            .unwrap();
+
+        let mut interner = CrcInterner::default();
+        let reprs = recv.matches_to_reprs(&matches, &mut interner).unwrap();
+        assert_eq!(2, reprs.len());
+
+        assert_eq!(Some("name"), reprs[0].field_name());
+        assert_eq!(
+            "hello",
+            reprs[0].as_node().unwrap().input().unwrap().as_str()
+        );
+        assert_eq!(Some("count"), reprs[1].field_name());
+        assert_eq!("5", reprs[1].as_node().unwrap().input().unwrap().as_str());
+    }
+
+    #[test]
+    #[cfg(feature = "util-clap")]
+    #[tracing_test::traced_test]
+    fn test_recv_to_command_fallback_value_parser() {
+        struct Fallback;
+
+        impl Recv for Fallback {
+            fn symbol() -> &'static str {
+                "fallback"
+            }
+        }
+
+        test_field!(
+            Fallback,
+            0,
+            "config",
+            std::path::PathBuf,
+            std::fs::File,
+            std::fs::File
+        );
+        test_field!(Fallback, 1, "verbose", String, (), ());
+
+        let config = <Fallback as Field<0>>::linker::<CrcInterner>()
+            .unwrap()
+            .link()
+            .unwrap();
+        let verbose = <Fallback as Field<1>>::linker::<CrcInterner>()
+            .unwrap()
+            .link()
+            .unwrap();
+        assert!(config.field_value_parser().is_none());
+
+        let repr = Fallback::link_recv(
+            NodeLevel::new().with_symbol(Fallback::symbol()),
+            vec![config, verbose],
+        )
+        .unwrap();
+        let recv = repr.as_recv().unwrap();
+
+        let command = recv.to_command();
+        assert!(command.get_arguments().any(|a| a.get_id() == "config"));
+        assert!(logs_contain(
+            "Field config has no value parser for ffi type file"
+        ));
+
+        let matches = command
+            .try_get_matches_from(["test", "--config", "runir.toml", "--verbose"])
+            .unwrap();
+        assert_eq!(
+            Some(&String::from("runir.toml")),
+            matches.get_one::<String>("config")
+        );
+        assert!(matches.get_flag("verbose"));
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn test_recv_to_json_schema() {
+        let name = <Test as Field<0>>::linker::<CrcInterner>()
+            .unwrap()
+            .link()
+            .unwrap();
+        let count = <Test as Field<1>>::linker::<CrcInterner>()
+            .unwrap()
+            .link()
+            .unwrap();
+
+        let repr = Test::link_recv(NodeLevel::new(), vec![name, count]).unwrap();
+        let schema = repr.as_recv().unwrap().to_json_schema();
+
+        assert_eq!("object", schema["type"]);
+
+        let properties = schema["properties"].as_object().unwrap();
+        assert_eq!(vec!["count", "name"], properties.keys().collect::<Vec<_>>());
+        assert_eq!("string", properties["name"]["type"]);
+        assert_eq!("integer", properties["count"]["type"]);
+    }
+
+    #[test]
+    fn test_field_diff() {
+        use crate::field_reprs;
+
+        macro_rules! fields {
+            ($owner:ident: $($offset:literal => $name:literal: $ty:ty),*) => {
+                struct $owner;
+
+                impl Recv for $owner {
+                    fn symbol() -> &'static str {
+                        stringify!($owner)
+                    }
+
+                    fn fields() -> anyhow::Result<Vec<Repr>> {
+                        field_reprs!($owner: $($offset),*)
+                    }
+                }
+
+                $(
+                    test_field!($owner, $offset, $name, $ty);
+                )*
+            };
+        }
+
+        fields!(Old: 0 => "a": String, 1 => "b": String);
+        fields!(New: 1 => "b": String, 2 => "c": String);
+        fields!(Changed: 1 => "b": u32, 2 => "c": String);
+
+        let old = Old::describe_recv().unwrap().as_recv().unwrap();
+        let new = New::describe_recv().unwrap().as_recv().unwrap();
+        let changed = Changed::describe_recv().unwrap().as_recv().unwrap();
+
+        let diff = old.field_diff(&new);
+        assert_eq!(vec!["c"], diff.added);
+        assert_eq!(vec!["a"], diff.removed);
+        assert!(diff.changed.is_empty());
+
+        let diff = new.field_diff(&changed);
+        assert!(diff.added.is_empty());
+        assert!(diff.removed.is_empty());
+        assert_eq!(vec!["b"], diff.changed);
+
+        assert_eq!(FieldDiff::default(), old.field_diff(&old));
+    }
+
+    #[test]
+    fn test_describe_recv() {
+        use crate::field_reprs;
+
+        struct Described;
+
+        impl Recv for Described {
+            fn symbol() -> &'static str {
+                "described"
+            }
+
+            fn fields() -> anyhow::Result<Vec<Repr>> {
+                field_reprs!(Described: 0, 1, 2)
+            }
+        }
+
+        test_field!(Described, 0, "name");
+        test_field!(Described, 1, "count", u32);
+        test_field!(Described, 2, "enabled", bool);
+
+        let repr = Described::describe_recv().unwrap();
+        let recv = repr.as_recv().unwrap();
+        assert_eq!("described", recv.name().unwrap().as_str());
+
+        let fields = recv.field_reprs();
+        assert_eq!(3, fields.len());
+        assert_eq!(Some("name"), fields[0].name());
+        assert_eq!(Some("count"), fields[1].name());
+        assert_eq!(Some("enabled"), fields[2].name());
+
+        // Fields can be linked w/ another interner
+        struct Entities;
+        struct EntityField;
+
+        test_field!(Entities, 0, "entity", String, EntityField, String);
+
+        let fields = field_reprs!(Entities as EntityInterner<CrcInterner>: 0).unwrap();
+        assert_eq!(1, fields.len());
+        assert!(fields[0].entity_id().is_some());
+
+        // Receivers that do not declare their fields are described w/o fields
+        struct Undeclared;
+
+        impl Recv for Undeclared {
+            fn symbol() -> &'static str {
+                "undeclared"
+            }
+        }
+
+        let repr = Undeclared::describe_recv().unwrap();
+        let recv = repr.as_recv().unwrap();
+        assert_eq!("undeclared", recv.name().unwrap().as_str());
+        assert!(recv.field_reprs().is_empty());
+    }
+
+    #[test]
+    fn test_recv_content_addressed() {
+        use crate::entropy::EntropyGuard;
+
+        struct Addressed;
+
+        impl Recv for Addressed {
+            fn symbol() -> &'static str {
+                "addressed"
+            }
+        }
+
+        let recv_level = |entropy: u64, content_addressed: bool| {
+            let _guard = EntropyGuard::new(entropy);
+            let fields = vec![
+                <Test as Field<0>>::linker::<CrcInterner>()
+                    .unwrap()
+                    .link()
+                    .unwrap(),
+                <Test as Field<1>>::linker::<CrcInterner>()
+                    .unwrap()
+                    .link()
+                    .unwrap(),
+            ];
+
+            let mut level = RecvLevel::new::<Addressed>(fields);
+            if content_addressed {
+                level = level.content_addressed();
+            }
+            level
+                .configure(&mut CrcInterner::default())
+                .unwrap()
+                .canonical()
+        };
+
+        let a = recv_level(0x2515_0001, true);
+        let b = recv_level(0x2515_0002, true);
+        assert_eq!(a, b);
+
+        let a = recv_level(0x2515_0001, false);
+        let b = recv_level(0x2515_0002, false);
+        assert_ne!(a, b);
+
+        // Fields that only differ by tags outside of the content hash are different content
+        struct OwnerA;
+        struct OwnerB;
+
+        test_field!(OwnerA, 0, "addressed_owner");
+        test_field!(OwnerB, 0, "addressed_owner");
+
+        let a = OwnerA::linker::<CrcInterner>().unwrap().link().unwrap();
+        let b = OwnerB::linker::<CrcInterner>().unwrap().link().unwrap();
+        assert_eq!(a.content_hash(), b.content_hash());
+
+        let a = RecvLevel::new::<Addressed>(vec![a])
+            .content_addressed()
+            .configure(&mut CrcInterner::default())
+            .unwrap();
+        let b = RecvLevel::new::<Addressed>(vec![b])
+            .content_addressed()
+            .configure(&mut CrcInterner::default())
+            .unwrap();
+        assert_ne!(a, b);
+        assert_ne!(RECV_FIELDS.clone(&a), RECV_FIELDS.clone(&b));
+        assert!(RecvRepr(a).is_content_addressed());
+
+        let fields = vec![OwnerA::linker::<CrcInterner>().unwrap().link().unwrap()];
+        let handle = RecvLevel::new::<Addressed>(fields)
+            .configure(&mut CrcInterner::default())
+            .unwrap();
+        assert!(!RecvRepr(handle).is_content_addressed());
+    }
+}
@@ -0,0 +1,224 @@
+use anyhow::anyhow;
+
+use crate::entity::ENTITY;
+use crate::prelude::*;
+
+/// Interner that re-applies an existing entity id to the handle created by an inner interner,
+///
+/// **Note** Mirrors `EntityInterner`, except the entity id is fixed rather than minted from a counter.
+///
+struct ReapplyEntity<'a, I: InternerFactory> {
+    /// Inner interner,
+    ///
+    inner: &'a mut I,
+    /// Entity id to re-apply,
+    ///
+    id: u64,
+}
+
+impl<I: InternerFactory> InternerFactory for ReapplyEntity<'_, I> {
+    fn push_tag<T: std::hash::Hash + Send + Sync + 'static>(
+        &mut self,
+        value: T,
+        assign: impl Fn(InternHandle) -> anyhow::Result<()> + Send + Sync + 'static,
+    ) {
+        self.inner.push_tag(value, assign);
+    }
+
+    fn push_tag_prehashed(
+        &mut self,
+        precomputed: u64,
+        assign: impl Fn(InternHandle) -> anyhow::Result<()> + Send + Sync + 'static,
+    ) {
+        self.inner.push_tag_prehashed(precomputed, assign);
+    }
+
+    fn set_level_flags(&mut self, flags: LevelFlags) {
+        self.inner.set_level_flags(flags);
+    }
+
+    fn set_data(&mut self, _: u64) {}
+
+    fn interner(&mut self) -> InternResult {
+        self.inner.set_data(self.id);
+        let handle = self.inner.interner()?;
+
+        ENTITY.assign_intern(handle, self.id)?;
+
+        Ok(handle)
+    }
+
+    fn family(&self) -> &'static str {
+        self.inner.family()
+    }
+}
+
+impl Repr {
+    /// Rebuilds this repr w/ interner by converting each level into the level it was configured from,
+    ///
+    /// Each level is resolved as a typed repr and rebuilt w/ `to_level` (i.e. `NodeRepr::to_level`), so the handle of
+    /// each relinked level is defined by the `configure` of that level. This is useful for compacting the handle space
+    /// after pruning, since the relinked repr only depends on the handles created by interner. If this repr is an
+    /// entity repr, the entity id of this repr is re-applied to the tail of the relinked repr, so that the relinked
+    /// repr is the same logical entity.
+    ///
+    /// **Note** Levels are resolved w/ `get_levels`, so previous levels that cannot be resolved w/ the entropy of the
+    /// current thread are not relinked. The entity id is re-applied by setting the data value of the tail w/ `set_data`,
+    /// so interner should not manage the data value itself, i.e. relinking w/ an `EntityInterner` mints a new entity id
+    /// for the tail instead.
+    ///
+    /// **Error** Returns an error if a level is not defined by this crate, or if the tags of a level could not be
+    /// resolved.
+    ///
+    pub fn relink(&self, interner: &mut impl InternerFactory) -> anyhow::Result<Repr> {
+        let entity_id = self.entity_id();
+        let levels = self.get_levels();
+
+        let mut repr = Repr::default();
+        for (idx, level) in levels.iter().enumerate() {
+            let relink = Relink {
+                repr: &mut repr,
+                interner: &mut *interner,
+                entity_id: entity_id.filter(|_| idx == levels.len() - 1),
+                idx,
+            };
+
+            match Repr::from_level(*level).view() {
+                LevelView::Resource(resource) => relink.upgrade(resource.to_level())?,
+                LevelView::Field(field) => relink.upgrade(field.to_level())?,
+                LevelView::Recv(recv) => relink.upgrade(recv.to_level())?,
+                LevelView::Dependency(dependency) => relink.upgrade(dependency.to_level())?,
+                LevelView::Variant(variant) => relink.upgrade(variant.to_level())?,
+                LevelView::Node(node) => relink.upgrade(Some(node.to_level()))?,
+                LevelView::Host(host) => relink.upgrade(host.to_level())?,
+                LevelView::Unknown(_) => Err(anyhow!(
+                    "Could not relink level {idx}, level is not defined by this crate"
+                ))?,
+            }
+        }
+
+        Ok(repr)
+    }
+}
+
+/// Upgrades a relinked repr w/ the next level,
+///
+struct Relink<'a, I: InternerFactory> {
+    /// Repr being relinked,
+    ///
+    repr: &'a mut Repr,
+    /// Interner to configure the level w/,
+    ///
+    interner: &'a mut I,
+    /// Entity id to re-apply to the level, if the level is the tail of an entity repr,
+    ///
+    entity_id: Option<u64>,
+    /// Index of the level being relinked,
+    ///
+    idx: usize,
+}
+
+impl<I: InternerFactory> Relink<'_, I> {
+    /// Upgrades the repr w/ level,
+    ///
+    /// **Error** Returns an error if the level could not be resolved.
+    ///
+    fn upgrade(self, level: Option<impl Level>) -> anyhow::Result<()> {
+        let level = level.ok_or_else(|| {
+            anyhow!(
+                "Could not relink level {}, tags could not be resolved",
+                self.idx
+            )
+        })?;
+
+        match self.entity_id {
+            Some(id) => self.repr.upgrade(
+                ReapplyEntity {
+                    inner: self.interner,
+                    id,
+                },
+                level,
+            ),
+            None => self.repr.upgrade(self.interner, level),
+        }
+    }
+}
+
+#[allow(unused)]
+mod tests {
+    use crate::prelude::*;
+
+    use crate::macros::test_field;
+
+    struct Relink;
+
+    test_field!(Relink, 0, "relink", u32);
+
+    #[test]
+    fn test_relink() {
+        let mut linker = Relink::linker::<CrcInterner>().unwrap();
+        linker
+            .push_level(
+                NodeLevel::new()
+                    .with_input("relink")
+                    .with_source("+ .relink relink")
+                    .with_block(3),
+            )
+            .unwrap();
+        linker.push_level(HostLevel::new("test://relink")).unwrap();
+        let repr = linker.link().unwrap();
+
+        // Relinking w/ the same interner produces the same handles
+        let relinked = repr.relink(&mut CrcInterner::default()).unwrap();
+        assert_eq!(repr, relinked);
+        assert_eq!(repr.resolve_all_tags(), relinked.resolve_all_tags());
+        assert_eq!(Some(3), relinked.as_node().unwrap().block_idx());
+    }
+
+    #[test]
+    fn test_relink_content_addressed() {
+        struct Owner;
+
+        impl Recv for Owner {
+            fn symbol() -> &'static str {
+                "owner"
+            }
+        }
+
+        let field = Relink::linker::<CrcInterner>().unwrap().link().unwrap();
+
+        let mut linker = Linker::new_crc::<Owner>();
+        linker
+            .push_level(RecvLevel::new::<Owner>(vec![field]).content_addressed())
+            .unwrap();
+        let repr = linker.link().unwrap();
+        assert!(repr.as_recv().unwrap().is_content_addressed());
+
+        // Relinking a content-addressed receiver hashes the field fingerprints rather than the field handles
+        let relinked = repr.relink(&mut CrcInterner::default()).unwrap();
+        assert_eq!(repr, relinked);
+        assert!(relinked.as_recv().unwrap().is_content_addressed());
+    }
+
+    #[test]
+    fn test_relink_entity() {
+        struct RelinkEntity;
+
+        let mut interner = EntityInterner::<CrcInterner>::default();
+        interner.reserve_ids(0x2522).unwrap();
+
+        let mut repr = Repr::default();
+        repr.upgrade(&mut interner, ResourceLevel::new::<RelinkEntity>())
+            .unwrap();
+        let entity_id = repr.entity_id();
+        assert_eq!(Some(0x2523), entity_id);
+
+        let relinked = repr.relink(&mut CrcInterner::default()).unwrap();
+        assert_eq!(entity_id, relinked.entity_id());
+        assert_eq!(repr.resolve_all_tags(), relinked.resolve_all_tags());
+        assert_eq!(
+            Some(std::any::type_name::<RelinkEntity>()),
+            relinked.as_resource().unwrap().type_name()
+        );
+    }
+}
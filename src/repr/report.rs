@@ -0,0 +1,113 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+
+use crate::prelude::*;
+
+/// Report of the entries in each intern table of this crate,
+///
+/// **Note** Returned by `interner_report`.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct InternerReport {
+    /// Report of each table,
+    ///
+    pub tables: Vec<TableReport>,
+}
+
+/// Report of the entries in an intern table,
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct TableReport {
+    /// Name of the table,
+    ///
+    pub name: &'static str,
+    /// Number of entries in the table,
+    ///
+    pub len: usize,
+    /// Approximate memory used by the entries of the table in bytes, see `InternTable::approx_memory`,
+    ///
+    pub approx_memory: usize,
+}
+
+impl InternerReport {
+    /// Adds a report of table,
+    ///
+    pub(crate) fn push<T: Send + Sync + 'static>(&mut self, table: &InternTable<T>) {
+        self.tables.push(TableReport {
+            name: table.name(),
+            len: table.len(),
+            approx_memory: table.approx_memory(),
+        });
+    }
+
+    /// Returns the report of the table w/ name,
+    ///
+    pub fn table(&self, name: &str) -> Option<&TableReport> {
+        self.tables.iter().find(|t| t.name == name)
+    }
+
+    /// Returns the total number of entries in every table,
+    ///
+    pub fn total_len(&self) -> usize {
+        self.tables.iter().map(|t| t.len).sum()
+    }
+
+    /// Returns the total approximate memory used by every table in bytes,
+    ///
+    pub fn total_approx_memory(&self) -> usize {
+        self.tables.iter().map(|t| t.approx_memory).sum()
+    }
+}
+
+impl Display for InternerReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "| table | len | approx memory |")?;
+        writeln!(f, "| --- | --- | --- |")?;
+        for table in self.tables.iter() {
+            writeln!(
+                f,
+                "| {} | {} | {} bytes |",
+                table.name, table.len, table.approx_memory
+            )?;
+        }
+        writeln!(
+            f,
+            "| **total** | {} | {} bytes |",
+            self.total_len(),
+            self.total_approx_memory()
+        )
+    }
+}
+
+#[allow(unused)]
+mod tests {
+    use crate::prelude::*;
+
+    use crate::macros::test_field;
+
+    struct Report;
+
+    test_field!(Report, 0, "report");
+
+    #[test]
+    fn test_interner_report() {
+        let mut linker = Report::linker::<CrcInterner>().unwrap();
+        linker
+            .push_level(NodeLevel::new().with_input("interner report"))
+            .unwrap();
+        linker.link().unwrap();
+
+        let report = interner_report();
+        for name in ["HANDLES", "TYPE_NAME", "FIELD_NAME", "INPUT"] {
+            let table = report.table(name).unwrap();
+            assert!(table.len > 0, "{name}");
+            assert!(table.approx_memory > 0, "{name}");
+        }
+        assert!(report.total_len() >= 4);
+
+        let display = report.to_string();
+        assert!(display.contains("| FIELD_NAME |"));
+        assert!(display.contains("| **total** |"));
+    }
+}
@@ -0,0 +1,257 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::prelude::*;
+use crate::repr::field;
+use crate::repr::host;
+use crate::repr::node;
+use crate::repr::node::SourceSpan;
+use crate::repr::resource;
+use crate::repr::HANDLES;
+
+/// Struct containing the resolved tags of a repr,
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedRepr {
+    /// Repr that was resolved,
+    ///
+    pub repr: Repr,
+    /// Kind of the repr,
+    ///
+    pub kind: ReprKind,
+    /// Intern handle of each level, ordered from the root to the tail,
+    ///
+    pub levels: Vec<InternHandle>,
+    /// Resource type name,
+    ///
+    pub type_name: Option<&'static str>,
+    /// Field name,
+    ///
+    pub field_name: Option<&'static str>,
+    /// Node input,
+    ///
+    pub input: Option<Arc<String>>,
+    /// Host address,
+    ///
+    pub address: Option<Arc<String>>,
+}
+
+impl ResolvedRepr {
+    /// Resolves the tags of a repr,
+    ///
+    /// **Note** Returns None if the tail of the repr has not been linked in `HANDLES`.
+    ///
+    pub fn resolve(repr: Repr) -> Option<Self> {
+        let (_, level) = repr.tail.node();
+        if HANDLES.copy(&level) != Some(repr.tail) {
+            return None;
+        }
+
+        Some(Self {
+            repr,
+            kind: repr.kind(),
+            levels: repr.get_levels(),
+            type_name: repr.as_resource().and_then(|r| r.type_name()),
+            field_name: repr.field_name(),
+            input: repr.as_node().and_then(|n| n.input()),
+            address: repr.as_host().and_then(|h| h.address()),
+        })
+    }
+}
+
+/// Struct containing every resource, field, node and host tag of a repr,
+///
+/// **Note** Resolved w/ `Repr::resolve_all_tags`, which borrows each table once rather than once per accessor.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ResolvedTagBundle {
+    /// Resource type name,
+    ///
+    pub type_name: Option<&'static str>,
+    /// Resource type size,
+    ///
+    pub type_size: Option<usize>,
+    /// Resource parse type name,
+    ///
+    pub parse_type_name: Option<&'static str>,
+    /// Resource ffi type name,
+    ///
+    pub ffi_type_name: Option<&'static str>,
+    /// Field owner name,
+    ///
+    pub owner_name: Option<&'static str>,
+    /// Field name,
+    ///
+    pub field_name: Option<&'static str>,
+    /// Field offset,
+    ///
+    pub field_offset: Option<usize>,
+    /// Node symbol,
+    ///
+    pub symbol: Option<Arc<String>>,
+    /// Node input,
+    ///
+    pub input: Option<Arc<String>>,
+    /// Node tag,
+    ///
+    pub tag: Option<Arc<String>>,
+    /// Node path,
+    ///
+    pub path: Option<Arc<String>>,
+    /// Node idx,
+    ///
+    pub idx: Option<usize>,
+    /// Node source,
+    ///
+    pub source: Option<Arc<String>>,
+    /// Node doc headers,
+    ///
+    pub doc_headers: Option<Arc<Vec<String>>>,
+    /// Node annotations,
+    ///
+    pub annotations: Option<Arc<BTreeMap<String, String>>>,
+    /// Node source span,
+    ///
+    pub span: Option<Arc<SourceSpan>>,
+    /// Node source relative path,
+    ///
+    pub relative: Option<Arc<PathBuf>>,
+    /// Node timestamp,
+    ///
+    pub timestamp: Option<u64>,
+    /// Host address,
+    ///
+    pub address: Option<Arc<String>>,
+    /// Host extensions,
+    ///
+    pub extensions: Option<Arc<Vec<Repr>>>,
+}
+
+impl Repr {
+    /// Resolves every resource, field, node and host tag of this repr in one pass,
+    ///
+    /// The levels are walked once and each table is borrowed once w/ `InternTable::get_many`, which is cheaper than
+    /// calling each accessor when most of the tags are needed, i.e. when rendering a repr.
+    ///
+    pub fn resolve_all_tags(&self) -> ResolvedTagBundle {
+        let levels = self.get_levels();
+
+        ResolvedTagBundle {
+            type_name: first(&resource::TYPE_NAME, &levels).map(|v| *v),
+            type_size: first(&resource::TYPE_SIZE, &levels).map(|v| *v),
+            parse_type_name: first(&resource::PARSE_TYPE_NAME, &levels).map(|v| *v),
+            ffi_type_name: first(&resource::FFI_TYPE_NAME, &levels).map(|v| *v),
+            owner_name: first(&field::OWNER_NAME, &levels).map(|v| *v),
+            field_name: first(&field::FIELD_NAME, &levels).map(|v| *v),
+            field_offset: first(&field::FIELD_OFFSET, &levels).map(|v| *v),
+            symbol: first(&node::SYMBOL, &levels),
+            input: first(&node::INPUT, &levels),
+            tag: first(&node::TAG, &levels),
+            path: first(&node::PATH, &levels),
+            idx: first(&node::NODE_IDX, &levels).map(|v| *v),
+            source: first(&node::SOURCE, &levels),
+            doc_headers: first(&node::DOC_HEADERS, &levels),
+            annotations: first(&node::ANNOTATIONS, &levels),
+            span: first(&node::SOURCE_SPAN, &levels),
+            relative: first(&node::SOURCE_RELATIVE, &levels),
+            timestamp: first(&node::TIMESTAMP, &levels).map(|v| *v),
+            address: first(&host::ADDRESS, &levels),
+            extensions: first(&host::EXTENSIONS, &levels),
+        }
+    }
+}
+
+/// Returns the value of the first level w/ a value in table,
+///
+fn first<T: Send + Sync + 'static>(
+    table: &InternTable<T>,
+    levels: &[InternHandle],
+) -> Option<Arc<T>> {
+    table.get_many(levels).into_iter().flatten().next()
+}
+
+impl InternTable<Vec<Repr>> {
+    /// Resolves each repr interned in the vector assigned to handle,
+    ///
+    /// Downstream crates can define their own tables of repr references, i.e.
+    /// `define_intern_table!(USER_REPR_REFS: Vec<Repr>)`, and resolve the nested reprs w/ this fn.
+    ///
+    /// **Note** Nested reprs are resolved from the tables populated when they were linked, so any nested
+    /// repr that has not been linked (i.e. restored from another process w/o a snapshot) is skipped.
+    ///
+    pub fn resolve_all(&self, handle: &InternHandle) -> Vec<ResolvedRepr> {
+        self.strong_ref(handle)
+            .iter()
+            .flat_map(|reprs| reprs.iter())
+            .filter_map(|r| ResolvedRepr::resolve(*r))
+            .collect()
+    }
+}
+
+#[allow(unused)]
+mod tests {
+    use crate::prelude::*;
+
+    use crate::macros::test_field;
+
+    struct Test;
+
+    test_field!(Test, 0, "resolved");
+
+    #[test]
+    fn test_resolve_all_tags() {
+        let mut linker = Test::linker::<CrcInterner>().unwrap();
+        linker
+            .push_level(
+                NodeLevel::new()
+                    .with_symbol("bundle")
+                    .with_input("resolve all tags")
+                    .with_tag("tagged")
+                    .with_path("bundle/path")
+                    .with_idx(2)
+                    .with_source("+ .bundle resolve all tags")
+                    .with_source_span(11..26)
+                    .with_doc_headers(vec!["Bundle header"])
+                    .with_timestamp(1234),
+            )
+            .unwrap();
+        let mut host = HostLevel::new("test://bundle");
+        host.set_extensions(vec![Linker::new_crc::<Test>().link().unwrap()]);
+        linker.push_level(host).unwrap();
+        let repr = linker.link().unwrap();
+
+        let bundle = repr.resolve_all_tags();
+
+        let resource = repr.as_resource().unwrap();
+        assert_eq!(resource.type_name(), bundle.type_name);
+        assert_eq!(resource.type_size(), bundle.type_size);
+        assert_eq!(resource.parse_type_name(), bundle.parse_type_name);
+        assert_eq!(resource.ffi_type_name(), bundle.ffi_type_name);
+
+        let field = repr.as_field().unwrap();
+        assert_eq!(field.owner_name(), bundle.owner_name);
+        assert_eq!(Some("resolved"), bundle.field_name);
+        assert_eq!(field.offset(), bundle.field_offset);
+
+        let node = repr.as_node().unwrap();
+        assert_eq!(node.symbol(), bundle.symbol);
+        assert_eq!(node.input(), bundle.input);
+        assert_eq!(node.tag(), bundle.tag);
+        assert_eq!(node.path(), bundle.path);
+        assert_eq!(Some(2), bundle.idx);
+        assert_eq!(node.source(), bundle.source);
+        assert_eq!(node.doc_headers(), bundle.doc_headers);
+        assert_eq!(node.annotations(), bundle.annotations);
+        assert_eq!(node.span(), bundle.span);
+        assert_eq!(node.relative(), bundle.relative);
+        assert_eq!(Some(1234), bundle.timestamp);
+
+        let host = repr.as_host().unwrap();
+        assert_eq!(host.address(), bundle.address);
+        assert_eq!(host.extensions(), bundle.extensions);
+
+        // Resolving the tags again produces the same bundle
+        assert_eq!(bundle, repr.resolve_all_tags());
+    }
+}
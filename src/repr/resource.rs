@@ -1,6 +1,12 @@
 use std::any::TypeId;
 use std::fs::File;
 use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use serde::Deserialize;
+use serde::Serialize;
+use tracing::warn;
 
 use crate::define_intern_table;
 use crate::push_tag;
@@ -16,15 +22,70 @@ define_intern_table!(TYPE_SIZE: usize);
 // Intern table for resource type ids
 define_intern_table!(TYPE_ID: TypeId);
 
+// Intern table for the trait names of dyn resources
+define_intern_table!(TRAIT_NAME: &'static str);
+
 // Intern table for resource parse type names
 define_intern_table!(PARSE_TYPE_NAME: &'static str);
 
+// Intern table for resource parse type ids
+define_intern_table!(PARSE_TYPE_ID: TypeId);
+
 // Intern table for ffi type name
 define_intern_table!(FFI_TYPE_NAME: &'static str);
 
+// Intern table for ffi type metadata
+define_intern_table!(FFI_INFO: FfiInfo);
+
+/// Clap value parser of an ffi type,
+///
+#[cfg(feature = "util-clap")]
+type FfiValueParser = Option<clap::builder::Resettable<clap::builder::ValueParser>>;
+
 // Intern table for ffi value parser
 #[cfg(feature = "util-clap")]
-define_intern_table!(FFI_VALUE_PARSER: Option<clap::builder::Resettable<clap::builder::ValueParser>>);
+define_intern_table!(FFI_VALUE_PARSER: FfiValueParser);
+
+// Reverse index from resource type id to reprs w/ that resource type
+#[cfg(feature = "type-index")]
+static TYPE_INDEX: std::sync::RwLock<std::collections::BTreeMap<TypeId, Vec<Repr>>> =
+    std::sync::RwLock::new(std::collections::BTreeMap::new());
+
+/// Adds a repr to the type index w/ the type id of its root level,
+///
+/// **Note** The root level is passed by the linker, so that the levels of the repr do not need to be walked.
+///
+#[cfg(feature = "type-index")]
+pub(crate) fn index_repr(repr: Repr, root: InternHandle) {
+    if let Some(type_id) = root.resource_type_id() {
+        if let Ok(mut index) = TYPE_INDEX.write() {
+            let reprs = index.entry(type_id).or_default();
+            if !reprs.contains(&repr) {
+                reprs.push(repr);
+            }
+        }
+    }
+}
+
+/// Returns all linked reprs whose resource is type T,
+///
+/// **Note** Requires the `type-index` feature. Reprs are indexed when `Linker::link` completes.
+///
+#[cfg(feature = "type-index")]
+pub fn reprs_of_type<T: 'static>() -> Vec<Repr> {
+    reprs_of_type_id(TypeId::of::<T>())
+}
+
+/// Returns all linked reprs whose resource has type id,
+///
+#[cfg(feature = "type-index")]
+pub(crate) fn reprs_of_type_id(type_id: TypeId) -> Vec<Repr> {
+    TYPE_INDEX
+        .read()
+        .ok()
+        .and_then(|index| index.get(&type_id).cloned())
+        .unwrap_or_default()
+}
 
 /// Resource level is the lowest level of representation,
 ///
@@ -34,25 +95,40 @@ define_intern_table!(FFI_VALUE_PARSER: Option<clap::builder::Resettable<clap::bu
 pub struct ResourceLevel {
     /// Rust type id assigned by the compiler,
     ///
-    type_id: Tag<TypeId>,
+    /// **Note** Dyn resources do not have a concrete type id.
+    ///
+    type_id: Option<Tag<TypeId, TypeId>>,
     /// Rust type name assigned by the compiler,
     ///
-    type_name: Tag<&'static str>,
+    type_name: Tag<&'static str, &'static str>,
     /// Type size assigned by the compiler,
     ///
-    type_size: Tag<usize>,
+    type_size: Tag<usize, usize>,
+    /// (Optional) Trait name of a dyn resource,
+    ///
+    trait_name: Option<Tag<&'static str, &'static str>>,
     /// Rust type name of the type used to parse node input,
     ///
-    parse_type: Option<Tag<&'static str>>,
+    parse_type: Option<Tag<&'static str, &'static str>>,
+    /// Rust type id of the type used to parse node input,
+    ///
+    /// **Note** Not part of the identity of the resource, since the parse type name already is.
+    ///
+    parse_type_id: Option<TypeId>,
     /// (Optional) FFI type name,
     ///
-    ffi_type: Option<Tag<&'static str>>,
+    ffi_type: Option<Tag<&'static str, &'static str>>,
+    /// (Optional) FFI type metadata,
+    ///
+    /// **Note** Not part of the identity of the resource, since it is derived from the ffi type.
+    ///
+    ffi_info: Option<FfiInfo>,
     /// (Optional) FFI clap value parser,
     ///
     /// **Note** Requires `util-clap` feature
     ///
     #[cfg(feature = "util-clap")]
-    ffi_value_parser: Option<Tag<Option<clap::builder::Resettable<clap::builder::ValueParser>>>>,
+    ffi_value_parser: Option<Tag<FfiValueParser, Arc<FfiValueParser>>>,
 }
 
 impl ResourceLevel {
@@ -61,11 +137,35 @@ impl ResourceLevel {
     #[inline]
     pub fn new<T: Send + Sync + 'static>() -> Self {
         Self {
-            type_id: Tag::new(&TYPE_ID, std::any::TypeId::of::<T>),
-            type_name: Tag::new(&TYPE_NAME, std::any::type_name::<T>),
-            type_size: Tag::new(&TYPE_SIZE, std::mem::size_of::<T>),
+            type_id: Some(Tag::new(&TYPE_ID, std::any::TypeId::of::<T>())),
+            type_name: Tag::new(&TYPE_NAME, std::any::type_name::<T>()),
+            type_size: Tag::new(&TYPE_SIZE, std::mem::size_of::<T>()),
+            trait_name: None,
             parse_type: None,
+            parse_type_id: None,
             ffi_type: None,
+            ffi_info: None,
+            #[cfg(feature = "util-clap")]
+            ffi_value_parser: None,
+        }
+    }
+
+    /// Creates a new type level representation for a resource behind a trait object,
+    ///
+    /// A dyn resource does not have a concrete type id, instead the trait name is recorded as both the type name and
+    /// the trait name, and the vtable hint (i.e. the size of the concrete type, or 0 if unknown) as the type size.
+    ///
+    #[inline]
+    pub fn new_dyn(trait_name: &'static str, vtable_hint: usize) -> Self {
+        Self {
+            type_id: None,
+            type_name: Tag::new(&TYPE_NAME, trait_name),
+            type_size: Tag::new(&TYPE_SIZE, vtable_hint),
+            trait_name: Some(Tag::new(&TRAIT_NAME, trait_name)),
+            parse_type: None,
+            parse_type_id: None,
+            ffi_type: None,
+            ffi_info: None,
             #[cfg(feature = "util-clap")]
             ffi_value_parser: None,
         }
@@ -74,37 +174,53 @@ impl ResourceLevel {
     /// Sets the resource parse type,
     ///
     #[inline]
-    pub fn set_parse_type<T>(&mut self) {
-        self.parse_type = Some(Tag::new(&PARSE_TYPE_NAME, std::any::type_name::<T>));
+    pub fn set_parse_type<T: 'static>(&mut self) {
+        self.parse_type = Some(Tag::new(&PARSE_TYPE_NAME, std::any::type_name::<T>()));
+        self.parse_type_id = Some(TypeId::of::<T>());
     }
 
     /// Sets the ffi type name,
     ///
     #[inline]
     pub fn set_ffi<T: FFI>(&mut self) {
-        self.ffi_type = Some(Tag::new(&FFI_TYPE_NAME, T::ffi_type_name));
+        self.ffi_type = Some(Tag::new(&FFI_TYPE_NAME, T::ffi_type_name()));
+        self.ffi_info = Some(FfiInfo::of::<T>());
 
         #[cfg(feature = "util-clap")]
         {
-            self.ffi_value_parser = Some(Tag::new(&FFI_VALUE_PARSER, T::value_parser))
+            self.ffi_value_parser = Some(Tag::new(&FFI_VALUE_PARSER, Arc::new(T::value_parser())))
         }
     }
 }
 
 impl Level for ResourceLevel {
     fn configure(&self, interner: &mut impl InternerFactory) -> InternResult {
-        push_tag!(interner, self.type_id);
+        if let Some(type_id) = self.type_id {
+            push_tag!(interner, type_id);
+        }
         push_tag!(interner, self.type_size);
         push_tag!(interner, self.type_name);
 
+        if let Some(trait_name) = self.trait_name {
+            push_tag!(interner, trait_name);
+        }
+
         if let Some(parse_type) = self.parse_type {
             push_tag!(interner, parse_type);
         }
 
+        if let Some(parse_type_id) = self.parse_type_id {
+            interner.push_assign(move |h| PARSE_TYPE_ID.assign_unique(h, parse_type_id));
+        }
+
         if let Some(ffi_type_name) = self.ffi_type {
             push_tag!(interner, ffi_type_name);
         }
 
+        if let Some(ffi_info) = self.ffi_info.clone() {
+            interner.push_assign(move |h| FFI_INFO.assign_unique(h, ffi_info.clone()));
+        }
+
         #[cfg(feature = "util-clap")]
         if let Some(ffi_value_parser) = self.ffi_value_parser.clone() {
             let ffi_vp_key = format!("{}_value_parser", self.type_name.value());
@@ -116,12 +232,12 @@ impl Level for ResourceLevel {
         interner.interner()
     }
 
-    type Mount = (TypeId, &'static str, usize);
+    type Mount = (Option<TypeId>, &'static str, usize);
 
     #[inline]
     fn mount(&self) -> Self::Mount {
         (
-            self.type_id.value(),
+            self.type_id.map(|t| t.value()),
             self.type_name.value(),
             self.type_size.value(),
         )
@@ -130,11 +246,46 @@ impl Level for ResourceLevel {
 
 /// Wrapper struct to access resource tags,
 ///
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Serialize, Deserialize)]
 pub struct ResourceRepr(pub(crate) InternHandle);
 
 impl ResourceRepr {
+    /// Returns the repr whose tail is this level,
+    ///
+    /// **Note** If this level has not been linked, the returned repr only contains this level.
+    ///
+    #[inline]
+    pub fn to_repr(&self) -> Repr {
+        Repr::from_level(self.0)
+    }
+
+    /// Returns a resource level w/ the tags of this resource,
+    ///
+    /// **Note** Returns None if the type name or type size of this resource could not be resolved.
+    ///
+    pub fn to_level(&self) -> Option<ResourceLevel> {
+        Some(ResourceLevel {
+            type_id: self.type_id().map(|t| Tag::new(&TYPE_ID, t)),
+            type_name: Tag::new(&TYPE_NAME, self.type_name()?),
+            type_size: Tag::new(&TYPE_SIZE, self.type_size()?),
+            trait_name: self.trait_name().map(|t| Tag::new(&TRAIT_NAME, t)),
+            parse_type: self
+                .parse_type_name()
+                .map(|t| Tag::new(&PARSE_TYPE_NAME, t)),
+            parse_type_id: self.parse_type_id(),
+            ffi_type: self.ffi_type_name().map(|t| Tag::new(&FFI_TYPE_NAME, t)),
+            ffi_info: FFI_INFO.clone(&self.0),
+            #[cfg(feature = "util-clap")]
+            ffi_value_parser: FFI_VALUE_PARSER
+                .strong_ref(&self.0)
+                .map(|vp| Tag::new(&FFI_VALUE_PARSER, vp)),
+        })
+    }
+
     /// Returns true if resource matches type,
     ///
+    /// **Note** Always false for dyn resources, since they do not have a concrete type id.
+    ///
     pub fn is_type<T: 'static>(&self) -> bool {
         self.type_name()
             .filter(|n| *n == std::any::type_name::<T>())
@@ -161,6 +312,20 @@ impl ResourceRepr {
             .is_some()
     }
 
+    /// Returns true if this resource was created w/ `ResourceLevel::new_dyn`,
+    ///
+    #[inline]
+    pub fn is_dyn(&self) -> bool {
+        TRAIT_NAME.contains(&self.0)
+    }
+
+    /// Returns the trait name of a dyn resource,
+    ///
+    #[inline]
+    pub fn trait_name(&self) -> Option<&'static str> {
+        self.0.resource_trait_name()
+    }
+
     /// Returns the tag value of the resource type name,
     ///
     #[inline]
@@ -189,6 +354,16 @@ impl ResourceRepr {
         self.0.resource_parse_type_name()
     }
 
+    /// Returns the parse type id,
+    ///
+    /// **Note** Only resources configured w/ `set_parse_type` have a parse type id, i.e. resources restored from a
+    /// snapshot only have a parse type name.
+    ///
+    #[inline]
+    pub fn parse_type_id(&self) -> Option<TypeId> {
+        self.0.resource_parse_type_id()
+    }
+
     /// Returns the FFI type name,
     ///
     #[inline]
@@ -196,6 +371,58 @@ impl ResourceRepr {
         self.0.resource_ffi_type_name()
     }
 
+    /// Returns the FFI status of this resource,
+    ///
+    /// **Note** Distinguishes a resource that was never configured w/ `set_ffi` from one configured w/ `set_ffi::<()>()`.
+    ///
+    pub fn ffi_status(&self) -> FfiStatus {
+        if !FFI_TYPE_NAME.contains(&self.0) {
+            return FfiStatus::NotConfigured;
+        }
+
+        match self.ffi_type_name() {
+            Some(name) if name == <() as FFI>::ffi_type_name() => FfiStatus::Unit,
+            Some(name) => FfiStatus::Typed(name),
+            None => FfiStatus::NotConfigured,
+        }
+    }
+
+    /// Returns the metadata of the ffi type of this resource,
+    ///
+    /// **Note** Only resources configured w/ `set_ffi` have ffi type metadata, i.e. resources restored from a schema
+    /// only have an ffi type name.
+    ///
+    #[inline]
+    pub fn ffi_info(&self) -> Option<Arc<FfiInfo>> {
+        FFI_INFO.strong_ref(&self.0)
+    }
+
+    /// Validates that the ffi type of this resource is coherent w/ its parse type,
+    ///
+    /// This is an opt-in lint, since `set_ffi` and `set_parse_type` are configured independently. Resources w/o an ffi
+    /// type or parse type, and ffi types that do not declare coherent parse types, are not checked.
+    ///
+    /// **Error** Returns an error if the parse type is not coherent w/ the ffi type, i.e. a `u32` ffi type parsed from
+    /// a `String`.
+    ///
+    pub fn validate_ffi_coherence(&self) -> anyhow::Result<()> {
+        let (Some(ffi_info), Some(parse_type_id)) = (self.ffi_info(), self.parse_type_id()) else {
+            return Ok(());
+        };
+        let ffi_type = ffi_info.name;
+        let parse_type = self.parse_type_name().unwrap_or_default();
+
+        match &ffi_info.coherent_parse_types {
+            Some(parse_types) if !parse_types.contains(&parse_type_id) => {
+                warn!("Resource ffi type {ffi_type} is not coherent w/ parse type {parse_type}");
+                Err(anyhow!(
+                    "Resource ffi type {ffi_type} is not coherent w/ parse type {parse_type}"
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Returns the FFI clap value parser,
     ///
     #[inline]
@@ -207,6 +434,21 @@ impl ResourceRepr {
     }
 }
 
+/// Enumeration of the FFI configuration of a resource,
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FfiStatus {
+    /// The resource was not configured w/ an FFI type,
+    ///
+    NotConfigured,
+    /// The resource was configured w/ the `unit` FFI type, which only communicates existence,
+    ///
+    Unit,
+    /// The resource was configured w/ an FFI type name,
+    ///
+    Typed(&'static str),
+}
+
 /// Trait to provide foreign function interface support,
 ///
 pub trait FFI {
@@ -218,6 +460,70 @@ pub trait FFI {
     ///
     #[cfg(feature = "util-clap")]
     fn value_parser() -> Option<clap::builder::Resettable<clap::builder::ValueParser>>;
+
+    /// JSON Schema type of a value of this ffi type, i.e. `string`,
+    ///
+    fn json_schema_type() -> Option<&'static str> {
+        None
+    }
+
+    /// Description of the value expected for this ffi type, i.e. `expected a string`,
+    ///
+    fn value_description() -> String {
+        format!("expected {}", Self::ffi_type_name())
+    }
+
+    /// True if a field of this ffi type can be expressed as a cli flag,
+    ///
+    /// **Note** Fields w/ a flag ffi type fall back to a bool value parser, instead of a string value parser.
+    ///
+    fn is_flag() -> bool {
+        false
+    }
+
+    /// Type ids of the parse types that are coherent w/ this ffi type, see `ResourceRepr::validate_ffi_coherence`,
+    ///
+    /// **Note** Returns None if the parse type of this ffi type is not checked, i.e. the `unit` ffi type only
+    /// communicates existence so any parse type is coherent.
+    ///
+    fn coherent_parse_types() -> Option<Vec<TypeId>> {
+        None
+    }
+}
+
+/// Metadata of an ffi type, collected from the `FFI` implementation of the type when a resource is configured,
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FfiInfo {
+    /// FFI type name,
+    ///
+    pub name: &'static str,
+    /// JSON Schema type of a value of the ffi type,
+    ///
+    pub json_schema_type: Option<&'static str>,
+    /// Description of the value expected for the ffi type,
+    ///
+    pub value_description: String,
+    /// True if a field of the ffi type can be expressed as a cli flag,
+    ///
+    pub is_flag: bool,
+    /// Type ids of the parse types that are coherent w/ the ffi type,
+    ///
+    pub coherent_parse_types: Option<Vec<TypeId>>,
+}
+
+impl FfiInfo {
+    /// Returns the metadata of an ffi type,
+    ///
+    pub fn of<T: FFI + ?Sized>() -> Self {
+        Self {
+            name: T::ffi_type_name(),
+            json_schema_type: T::json_schema_type(),
+            value_description: T::value_description(),
+            is_flag: T::is_flag(),
+            coherent_parse_types: T::coherent_parse_types(),
+        }
+    }
 }
 
 impl FFI for () {
@@ -230,6 +536,18 @@ impl FFI for () {
     {
         None
     }
+
+    fn json_schema_type() -> Option<&'static str> {
+        Some("null")
+    }
+
+    fn value_description() -> String {
+        "expected no value".to_string()
+    }
+
+    fn is_flag() -> bool {
+        true
+    }
 }
 
 impl FFI for String {
@@ -243,6 +561,18 @@ impl FFI for String {
 
         Some(clap::value_parser!(Self).into_resettable())
     }
+
+    fn json_schema_type() -> Option<&'static str> {
+        Some("string")
+    }
+
+    fn value_description() -> String {
+        "expected a string".to_string()
+    }
+
+    fn coherent_parse_types() -> Option<Vec<TypeId>> {
+        Some(vec![TypeId::of::<String>()])
+    }
 }
 
 impl FFI for PathBuf {
@@ -256,6 +586,18 @@ impl FFI for PathBuf {
 
         Some(clap::value_parser!(Self).into_resettable())
     }
+
+    fn json_schema_type() -> Option<&'static str> {
+        Some("string")
+    }
+
+    fn value_description() -> String {
+        "expected a path".to_string()
+    }
+
+    fn coherent_parse_types() -> Option<Vec<TypeId>> {
+        Some(vec![TypeId::of::<PathBuf>(), TypeId::of::<String>()])
+    }
 }
 
 impl FFI for File {
@@ -267,6 +609,18 @@ impl FFI for File {
     fn value_parser() -> Option<clap::builder::Resettable<clap::builder::ValueParser>> {
         None
     }
+
+    fn json_schema_type() -> Option<&'static str> {
+        Some("string")
+    }
+
+    fn value_description() -> String {
+        "expected a path to a file".to_string()
+    }
+
+    fn coherent_parse_types() -> Option<Vec<TypeId>> {
+        Some(vec![TypeId::of::<PathBuf>(), TypeId::of::<File>()])
+    }
 }
 
 impl FFI for bool {
@@ -280,6 +634,22 @@ impl FFI for bool {
 
         Some(clap::value_parser!(Self).into_resettable())
     }
+
+    fn json_schema_type() -> Option<&'static str> {
+        Some("boolean")
+    }
+
+    fn value_description() -> String {
+        "expected true or false".to_string()
+    }
+
+    fn is_flag() -> bool {
+        true
+    }
+
+    fn coherent_parse_types() -> Option<Vec<TypeId>> {
+        Some(vec![TypeId::of::<bool>()])
+    }
 }
 
 impl FFI for u8 {
@@ -293,6 +663,14 @@ impl FFI for u8 {
 
         Some(clap::value_parser!(Self).into_resettable())
     }
+
+    fn json_schema_type() -> Option<&'static str> {
+        Some("integer")
+    }
+
+    fn coherent_parse_types() -> Option<Vec<TypeId>> {
+        Some(vec![TypeId::of::<u8>()])
+    }
 }
 
 impl FFI for u16 {
@@ -306,6 +684,14 @@ impl FFI for u16 {
 
         Some(clap::value_parser!(Self).into_resettable())
     }
+
+    fn json_schema_type() -> Option<&'static str> {
+        Some("integer")
+    }
+
+    fn coherent_parse_types() -> Option<Vec<TypeId>> {
+        Some(vec![TypeId::of::<u16>()])
+    }
 }
 
 impl FFI for u32 {
@@ -319,6 +705,14 @@ impl FFI for u32 {
 
         Some(clap::value_parser!(Self).into_resettable())
     }
+
+    fn json_schema_type() -> Option<&'static str> {
+        Some("integer")
+    }
+
+    fn coherent_parse_types() -> Option<Vec<TypeId>> {
+        Some(vec![TypeId::of::<u32>()])
+    }
 }
 
 impl FFI for u64 {
@@ -332,6 +726,14 @@ impl FFI for u64 {
 
         Some(clap::value_parser!(Self).into_resettable())
     }
+
+    fn json_schema_type() -> Option<&'static str> {
+        Some("integer")
+    }
+
+    fn coherent_parse_types() -> Option<Vec<TypeId>> {
+        Some(vec![TypeId::of::<u64>()])
+    }
 }
 
 impl FFI for i8 {
@@ -345,6 +747,14 @@ impl FFI for i8 {
 
         Some(clap::value_parser!(Self).into_resettable())
     }
+
+    fn json_schema_type() -> Option<&'static str> {
+        Some("integer")
+    }
+
+    fn coherent_parse_types() -> Option<Vec<TypeId>> {
+        Some(vec![TypeId::of::<i8>()])
+    }
 }
 
 impl FFI for i16 {
@@ -358,6 +768,14 @@ impl FFI for i16 {
 
         Some(clap::value_parser!(Self).into_resettable())
     }
+
+    fn json_schema_type() -> Option<&'static str> {
+        Some("integer")
+    }
+
+    fn coherent_parse_types() -> Option<Vec<TypeId>> {
+        Some(vec![TypeId::of::<i16>()])
+    }
 }
 
 impl FFI for i32 {
@@ -371,6 +789,14 @@ impl FFI for i32 {
 
         Some(clap::value_parser!(Self).into_resettable())
     }
+
+    fn json_schema_type() -> Option<&'static str> {
+        Some("integer")
+    }
+
+    fn coherent_parse_types() -> Option<Vec<TypeId>> {
+        Some(vec![TypeId::of::<i32>()])
+    }
 }
 
 impl FFI for i64 {
@@ -384,6 +810,14 @@ impl FFI for i64 {
 
         Some(clap::value_parser!(Self).into_resettable())
     }
+
+    fn json_schema_type() -> Option<&'static str> {
+        Some("integer")
+    }
+
+    fn coherent_parse_types() -> Option<Vec<TypeId>> {
+        Some(vec![TypeId::of::<i64>()])
+    }
 }
 
 impl FFI for f32 {
@@ -397,6 +831,14 @@ impl FFI for f32 {
 
         Some(clap::value_parser!(Self).into_resettable())
     }
+
+    fn json_schema_type() -> Option<&'static str> {
+        Some("number")
+    }
+
+    fn coherent_parse_types() -> Option<Vec<TypeId>> {
+        Some(vec![TypeId::of::<f32>()])
+    }
 }
 
 impl FFI for f64 {
@@ -410,4 +852,180 @@ impl FFI for f64 {
 
         Some(clap::value_parser!(Self).into_resettable())
     }
+
+    fn json_schema_type() -> Option<&'static str> {
+        Some("number")
+    }
+
+    fn coherent_parse_types() -> Option<Vec<TypeId>> {
+        Some(vec![TypeId::of::<f64>()])
+    }
+}
+
+#[allow(unused)]
+mod tests {
+    use crate::prelude::*;
+
+    use crate::macros::test_field;
+
+    #[test]
+    fn test_ffi_status() {
+        struct NoFfi;
+        struct UnitFfi;
+        struct StringFfi;
+
+        let no_ffi = Linker::new_crc::<NoFfi>().link().unwrap();
+        assert_eq!(
+            FfiStatus::NotConfigured,
+            no_ffi.as_resource().unwrap().ffi_status()
+        );
+
+        let mut unit = ResourceLevel::new::<UnitFfi>();
+        unit.set_ffi::<()>();
+        let mut linker = Linker::<CrcInterner>::default();
+        linker.push_level(unit).unwrap();
+        let unit = linker.link().unwrap();
+        assert_eq!(FfiStatus::Unit, unit.as_resource().unwrap().ffi_status());
+
+        let mut string = ResourceLevel::new::<StringFfi>();
+        string.set_ffi::<String>();
+        let mut linker = Linker::<CrcInterner>::default();
+        linker.push_level(string).unwrap();
+        let string = linker.link().unwrap();
+        assert_eq!(
+            FfiStatus::Typed("string"),
+            string.as_resource().unwrap().ffi_status()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "type-index")]
+    fn test_reprs_of_type() {
+        // Local types are used since the index is shared w/ other tests
+        struct A;
+        struct B;
+
+        let a = Linker::new_crc::<A>().link().unwrap();
+
+        let mut dependency = Linker::new_crc::<A>();
+        dependency
+            .push_level(DependencyLevel::new("type index"))
+            .unwrap();
+        let dependency = dependency.link().unwrap();
+
+        let b = Linker::new_crc::<B>().link().unwrap();
+
+        // Linking again should not duplicate the entry
+        Linker::new_crc::<A>().link().unwrap();
+
+        assert_eq!(vec![a, dependency], reprs_of_type::<A>());
+        assert_eq!(vec![b], reprs_of_type::<B>());
+    }
+
+    #[test]
+    fn test_dyn_resource() {
+        trait Shape {}
+        struct Circle {
+            radius: f64,
+        }
+        impl Shape for Circle {}
+
+        let mut linker = Linker::<CrcInterner>::default();
+        linker
+            .push_level(ResourceLevel::new_dyn(
+                "dyn runir::Shape",
+                std::mem::size_of::<Circle>(),
+            ))
+            .unwrap();
+        let repr = linker.link().unwrap();
+        let resource = repr.as_resource().unwrap();
+
+        assert!(resource.is_dyn());
+        assert_eq!(Some("dyn runir::Shape"), resource.trait_name());
+        assert_eq!(Some("dyn runir::Shape"), resource.type_name());
+        assert_eq!(Some(8), resource.type_size());
+        assert_eq!(None, resource.type_id());
+
+        // The vtable hint is part of the identity of a dyn resource
+        let mut linker = Linker::<CrcInterner>::default();
+        linker
+            .push_level(ResourceLevel::new_dyn("dyn runir::Shape", 0))
+            .unwrap();
+        let unknown = linker.link().unwrap();
+        assert_eq!(Some(0), unknown.as_resource().unwrap().type_size());
+        assert_ne!(repr, unknown);
+
+        // Concrete type checks fail for dyn resources
+        assert!(!resource.is_type::<Circle>());
+        assert!(!resource.is_type::<Box<dyn Shape>>());
+
+        let concrete = Linker::new_crc::<Circle>().link().unwrap();
+        let concrete = concrete.as_resource().unwrap();
+        assert!(!concrete.is_dyn());
+        assert_eq!(None, concrete.trait_name());
+        assert!(concrete.is_type::<Circle>());
+    }
+
+    #[test]
+    fn test_validate_ffi_coherence() {
+        struct Coherent;
+        struct Incoherent;
+
+        test_field!(Coherent, 0, "coherent", u32);
+        test_field!(Incoherent, 0, "incoherent", String, u32, u32);
+
+        let coherent = Coherent::linker::<CrcInterner>().unwrap().link().unwrap();
+        coherent.validate_ffi_coherence().unwrap();
+        assert_eq!(
+            Some(std::any::TypeId::of::<u32>()),
+            coherent.as_resource().unwrap().parse_type_id()
+        );
+
+        let incoherent = Incoherent::linker::<CrcInterner>().unwrap().link().unwrap();
+        let err = incoherent.validate_ffi_coherence().unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("ffi type u32 is not coherent w/ parse type alloc::string::String"));
+
+        // Resources w/o an ffi type are not checked
+        struct NoFfi;
+        let no_ffi = Linker::new_crc::<NoFfi>().link().unwrap();
+        no_ffi.validate_ffi_coherence().unwrap();
+    }
+
+    #[test]
+    fn test_ffi_info() {
+        struct Custom;
+
+        impl FFI for Custom {
+            fn ffi_type_name() -> &'static str {
+                "custom"
+            }
+
+            #[cfg(feature = "util-clap")]
+            fn value_parser() -> Option<clap::builder::Resettable<clap::builder::ValueParser>> {
+                None
+            }
+        }
+
+        test_field!(Custom, 0, "custom", String, String, Custom);
+
+        // Downstream ffi types fall back to the default metadata
+        let custom = Custom::linker::<CrcInterner>().unwrap().link().unwrap();
+        let info = custom.ffi_info().unwrap();
+        assert_eq!(FfiInfo::of::<Custom>(), *info);
+        assert_eq!(None, info.json_schema_type);
+        assert!(!info.is_flag);
+        assert_eq!(
+            Some("expected custom".to_string()),
+            custom.value_parser_description()
+        );
+        custom.validate_ffi_coherence().unwrap();
+
+        let unit = FfiInfo::of::<()>();
+        assert_eq!("unit", unit.name);
+        assert!(unit.is_flag);
+        assert_eq!(None, unit.coherent_parse_types);
+        assert_eq!(Some("integer"), FfiInfo::of::<u32>().json_schema_type);
+    }
 }
@@ -0,0 +1,368 @@
+use std::collections::BTreeSet;
+use std::sync::Mutex;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::prelude::*;
+use crate::push_tag;
+use crate::repr::field::FIELD_NAME;
+use crate::repr::field::FIELD_OFFSET;
+use crate::repr::field::OWNER_NAME;
+use crate::repr::resource::FFI_TYPE_NAME;
+use crate::repr::resource::PARSE_TYPE_NAME;
+use crate::repr::resource::TYPE_NAME;
+
+/// Schema of a receiver w/ every tag resolved into owned values,
+///
+/// Unlike a `RecvRepr`, an owned schema does not depend on the intern tables of the current process, so it can be
+/// serialized and sent to a peer, which can rebuild the receiver w/ `OwnedSchema::intern`.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnedSchema {
+    /// Name of the receiver,
+    ///
+    pub name: String,
+    /// Fields owned by the receiver,
+    ///
+    pub fields: Vec<OwnedField>,
+}
+
+/// Field of an owned schema,
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnedField {
+    /// Field name,
+    ///
+    pub name: String,
+    /// Field offset,
+    ///
+    pub offset: usize,
+    /// Name of the type that owns the field,
+    ///
+    pub owner_name: String,
+    /// Resource type name of the field,
+    ///
+    pub type_name: Option<String>,
+    /// Ffi type name of the field,
+    ///
+    pub ffi_type: Option<String>,
+    /// Parse type name of the field,
+    ///
+    pub parse_type: Option<String>,
+    /// Field help,
+    ///
+    pub help: Option<String>,
+}
+
+impl RecvRepr {
+    /// Returns the schema of this receiver w/ every tag resolved into owned values,
+    ///
+    /// **Note** Fields w/o a field name are skipped.
+    ///
+    pub fn to_owned_schema(&self) -> OwnedSchema {
+        let fields = self
+            .fields()
+            .iter()
+            .flat_map(|f| f.iter())
+            .filter_map(|field| {
+                let name = field.field_name()?;
+                let resource = field.as_resource();
+                let owner = field.as_field();
+
+                Some(OwnedField {
+                    name: name.to_string(),
+                    offset: owner.as_ref().and_then(|f| f.offset()).unwrap_or_default(),
+                    owner_name: owner
+                        .as_ref()
+                        .and_then(|f| f.owner_name())
+                        .unwrap_or_default()
+                        .to_string(),
+                    type_name: resource
+                        .as_ref()
+                        .and_then(|r| r.type_name())
+                        .map(str::to_string),
+                    ffi_type: field.ffi_type().map(str::to_string),
+                    parse_type: resource
+                        .as_ref()
+                        .and_then(|r| r.parse_type_name())
+                        .map(str::to_string),
+                    help: field.field_help(),
+                })
+            })
+            .collect();
+
+        OwnedSchema {
+            name: self.name().map(|n| n.to_string()).unwrap_or_default(),
+            fields,
+        }
+    }
+}
+
+impl OwnedSchema {
+    /// Interns each field of this schema and links a receiver w/ the interned fields,
+    ///
+    /// Each field is rebuilt as a resource level tagged w/ the type names of the field, a field level tagged w/ the
+    /// owner name, offset and field name, and a node level tagged w/ the field help if the field has help.
+    ///
+    /// **Note** Type ids and sizes are not part of an owned schema, so the rebuilt reprs will not be equal to the reprs
+    /// the schema was created from. Type and field names are leaked, since the tables they are interned in only hold
+    /// static strings. Each distinct name is only leaked once, so interning the same schema again does not leak.
+    ///
+    /// **Error** Returns an error if leaking a name would exceed `MAX_LEAKED_BYTES`, since a schema may be received
+    /// from a peer that sends many distinct names.
+    ///
+    pub fn intern(&self, interner: &mut impl InternerFactory) -> anyhow::Result<Repr> {
+        let mut fields = vec![];
+        for field in self.fields.iter() {
+            let mut repr = Repr::default();
+            repr.upgrade(
+                &mut *interner,
+                OwnedResourceLevel {
+                    type_name: field.type_name.as_deref().map(leak).transpose()?,
+                    parse_type: field.parse_type.as_deref().map(leak).transpose()?,
+                    ffi_type: field.ffi_type.as_deref().map(leak).transpose()?,
+                },
+            )?;
+            repr.upgrade(
+                &mut *interner,
+                OwnedFieldLevel {
+                    owner_name: leak(&field.owner_name)?,
+                    offset: field.offset,
+                    name: leak(&field.name)?,
+                },
+            )?;
+
+            if let Some(help) = field.help.as_ref() {
+                repr.upgrade(
+                    &mut *interner,
+                    NodeLevel::new().with_doc_headers(vec![help.as_str()]),
+                )?;
+            }
+
+            fields.push(repr);
+        }
+
+        let mut repr = Repr::default();
+        repr.upgrade(
+            &mut *interner,
+            OwnedResourceLevel {
+                type_name: Some(leak(&self.name)?),
+                parse_type: None,
+                ffi_type: None,
+            },
+        )?;
+        repr.upgrade(&mut *interner, RecvLevel::named(self.name.as_str(), fields))?;
+        repr.upgrade(
+            &mut *interner,
+            NodeLevel::new().with_symbol(self.name.as_str()),
+        )?;
+        Ok(repr)
+    }
+}
+
+/// Maximum number of bytes that can be leaked for static copies of strings, see `OwnedSchema::intern`,
+///
+pub const MAX_LEAKED_BYTES: usize = 16 * 1024 * 1024;
+
+/// Static copies of the strings leaked by `OwnedSchema::intern` and restored snapshots,
+///
+struct Leaked {
+    /// Static copy of each distinct string,
+    ///
+    strings: BTreeSet<&'static str>,
+    /// Total number of bytes leaked,
+    ///
+    bytes: usize,
+}
+
+// Strings leaked by `leak`
+static LEAKED: Mutex<Leaked> = Mutex::new(Leaked {
+    strings: BTreeSet::new(),
+    bytes: 0,
+});
+
+/// Returns a static copy of a string,
+///
+/// **Note** Each distinct string is only leaked once, later calls return the existing static copy.
+///
+/// **Error** Returns an error if leaking the string would exceed `MAX_LEAKED_BYTES`.
+///
+pub(crate) fn leak(value: &str) -> anyhow::Result<&'static str> {
+    let mut leaked = LEAKED.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(existing) = leaked.strings.get(value) {
+        return Ok(existing);
+    }
+
+    let bytes = leaked.bytes + value.len();
+    if bytes > MAX_LEAKED_BYTES {
+        Err(anyhow::anyhow!(
+            "Cannot leak {} more bytes, the limit of {MAX_LEAKED_BYTES} leaked bytes has been reached",
+            value.len()
+        ))?;
+    }
+
+    let value: &'static str = Box::leak(value.to_string().into_boxed_str());
+    leaked.strings.insert(value);
+    leaked.bytes = bytes;
+    Ok(value)
+}
+
+/// Resource level rebuilt from the type names of an owned field,
+///
+struct OwnedResourceLevel {
+    /// Resource type name,
+    ///
+    type_name: Option<&'static str>,
+    /// Parse type name,
+    ///
+    parse_type: Option<&'static str>,
+    /// Ffi type name,
+    ///
+    ffi_type: Option<&'static str>,
+}
+
+impl Level for OwnedResourceLevel {
+    fn configure(&self, interner: &mut impl InternerFactory) -> InternResult {
+        if let Some(type_name) = self.type_name {
+            push_tag!(interner, Tag::<_, &'static str>::new(&TYPE_NAME, type_name));
+        }
+
+        if let Some(parse_type) = self.parse_type {
+            push_tag!(
+                interner,
+                Tag::<_, &'static str>::new(&PARSE_TYPE_NAME, parse_type)
+            );
+        }
+
+        if let Some(ffi_type) = self.ffi_type {
+            push_tag!(
+                interner,
+                Tag::<_, &'static str>::new(&FFI_TYPE_NAME, ffi_type)
+            );
+        }
+
+        interner.set_level_flags(LevelFlags::ROOT);
+
+        interner.interner()
+    }
+
+    type Mount = ();
+
+    fn mount(&self) -> Self::Mount {}
+}
+
+/// Field level rebuilt from an owned field,
+///
+struct OwnedFieldLevel {
+    /// Name of the type that owns the field,
+    ///
+    owner_name: &'static str,
+    /// Field offset,
+    ///
+    offset: usize,
+    /// Field name,
+    ///
+    name: &'static str,
+}
+
+impl Level for OwnedFieldLevel {
+    fn configure(&self, interner: &mut impl InternerFactory) -> InternResult {
+        push_tag!(
+            interner,
+            Tag::<_, &'static str>::new(&OWNER_NAME, self.owner_name)
+        );
+        push_tag!(interner, Tag::<_, usize>::new(&FIELD_OFFSET, self.offset));
+        push_tag!(
+            interner,
+            Tag::<_, &'static str>::new(&FIELD_NAME, self.name)
+        );
+
+        interner.set_level_flags(LevelFlags::LEVEL_1);
+
+        interner.interner()
+    }
+
+    type Mount = ();
+
+    fn mount(&self) -> Self::Mount {}
+}
+
+#[allow(unused)]
+mod tests {
+    use crate::prelude::*;
+
+    use crate::macros::test_field;
+
+    struct Schema;
+
+    impl Recv for Schema {
+        fn symbol() -> &'static str {
+            "schema"
+        }
+    }
+
+    test_field!(Schema, 0, "label");
+    test_field!(Schema, 1, "retries", u32);
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn test_owned_schema_round_trip() {
+        let mut label = <Schema as Field<0>>::linker::<CrcInterner>().unwrap();
+        label
+            .push_level(NodeLevel::new().with_doc_headers(vec!["Label of the schema"]))
+            .unwrap();
+        let label = label.link().unwrap();
+        let retries = <Schema as Field<1>>::linker::<CrcInterner>()
+            .unwrap()
+            .link()
+            .unwrap();
+
+        let recv = Schema::link_recv(NodeLevel::new().with_symbol("schema"), vec![label, retries])
+            .unwrap()
+            .as_recv()
+            .unwrap();
+
+        let schema = recv.to_owned_schema();
+        assert_eq!("schema", schema.name);
+        assert_eq!(2, schema.fields.len());
+        assert_eq!("label", schema.fields[0].name);
+        assert_eq!(
+            Some("Label of the schema".to_string()),
+            schema.fields[0].help
+        );
+        assert_eq!("retries", schema.fields[1].name);
+        assert_eq!(1, schema.fields[1].offset);
+        assert_eq!(Some("u32"), schema.fields[1].parse_type.as_deref());
+
+        let json = serde_json::to_string(&schema).unwrap();
+
+        // Rebuild the schema in a thread w/ a different entropy
+        let rebuilt = std::thread::spawn(move || {
+            let schema = serde_json::from_str::<OwnedSchema>(&json).unwrap();
+            let repr = schema.intern(&mut CrcInterner::default()).unwrap();
+            repr.as_recv().unwrap().to_owned_schema()
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(schema, rebuilt);
+    }
+
+    #[test]
+    fn test_leak_dedup() {
+        let name = String::from("test_leak_dedup");
+        let leaked = super::leak(&name).unwrap();
+        assert_eq!("test_leak_dedup", leaked);
+
+        // Leaking an equal string returns the existing static copy
+        assert!(std::ptr::eq(
+            leaked,
+            super::leak("test_leak_dedup").unwrap()
+        ));
+
+        // Strings that would exceed the limit are not leaked
+        let large = "x".repeat(super::MAX_LEAKED_BYTES + 1);
+        assert!(super::leak(&large).is_err());
+    }
+}
@@ -0,0 +1,836 @@
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::io::Write;
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tracing::warn;
+
+use crate::entity::ENTITY;
+use crate::interner::INTERNER_FAMILY;
+use crate::prelude::*;
+use crate::repr::dependency::*;
+use crate::repr::field::*;
+use crate::repr::host::*;
+use crate::repr::node::*;
+use crate::repr::recv::*;
+use crate::repr::resource::*;
+use crate::repr::variant::*;
+use crate::repr::HANDLES;
+
+/// Magic bytes at the start of every snapshot,
+///
+const MAGIC: [u8; 4] = *b"RNIR";
+
+/// Current version of the snapshot layout,
+///
+const VERSION: u8 = 1;
+
+/// Compression codec applied to the frames of a snapshot,
+///
+/// **Note** Compression wraps the whole stream of table frames, the header is always uncompressed.
+///
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    /// Frames are not compressed,
+    ///
+    #[default]
+    None,
+    /// Frames are compressed w/ zstd,
+    ///
+    #[cfg(feature = "zstd")]
+    Zstd,
+    /// Frames are compressed w/ gzip,
+    ///
+    #[cfg(feature = "gzip")]
+    Gzip,
+}
+
+impl Compression {
+    /// Returns the id of the codec recorded in the snapshot header,
+    ///
+    pub const fn id(&self) -> u8 {
+        match self {
+            Compression::None => 0,
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => 1,
+            #[cfg(feature = "gzip")]
+            Compression::Gzip => 2,
+        }
+    }
+
+    /// Writes the frames of a snapshot compressed w/ this codec,
+    ///
+    fn write_tables(&self, w: &mut impl Write, format: &impl ExportFormat) -> anyhow::Result<()> {
+        match self {
+            Compression::None => write_tables(w, format),
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => {
+                let mut encoder = zstd::Encoder::new(w, 0)?;
+                write_tables(&mut encoder, format)?;
+                encoder.finish()?;
+                Ok(())
+            }
+            #[cfg(feature = "gzip")]
+            Compression::Gzip => {
+                let mut encoder = flate2::write::GzEncoder::new(w, flate2::Compression::default());
+                write_tables(&mut encoder, format)?;
+                encoder.finish()?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns a reader that decompresses the frames of a snapshot w/ the codec recorded in the header,
+    ///
+    /// **Error** Returns an error if the codec is unknown, or was not enabled when this crate was compiled.
+    ///
+    fn decoder<'a>(id: u8, r: &'a mut impl Read) -> anyhow::Result<Box<dyn Read + 'a>> {
+        match id {
+            0 => Ok(Box::new(r)),
+            #[cfg(feature = "zstd")]
+            1 => Ok(Box::new(zstd::Decoder::new(r)?)),
+            #[cfg(feature = "gzip")]
+            2 => Ok(Box::new(flate2::read::GzDecoder::new(r))),
+            _ => Err(anyhow!("Unsupported snapshot compression {id}")),
+        }
+    }
+}
+
+/// Trait for a serialization format that can be used to encode snapshot tables,
+///
+pub trait ExportFormat {
+    /// Id of the format recorded in the snapshot header,
+    ///
+    const ID: u8;
+
+    /// Encodes a value,
+    ///
+    fn encode<T: Serialize>(&self, value: &T) -> anyhow::Result<Vec<u8>>;
+
+    /// Decodes a value,
+    ///
+    fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> anyhow::Result<T>;
+}
+
+/// Bincode export format, optimized for speed,
+///
+#[derive(Default, Debug, Clone, Copy)]
+pub struct Bincode;
+
+impl ExportFormat for Bincode {
+    const ID: u8 = 1;
+
+    fn encode<T: Serialize>(&self, value: &T) -> anyhow::Result<Vec<u8>> {
+        Ok(bincode::serialize(value)?)
+    }
+
+    fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> anyhow::Result<T> {
+        Ok(bincode::deserialize(bytes)?)
+    }
+}
+
+/// CBOR export format, self-describing which makes it safer to read across versions,
+///
+#[cfg(feature = "cbor")]
+#[derive(Default, Debug, Clone, Copy)]
+pub struct Cbor;
+
+#[cfg(feature = "cbor")]
+impl ExportFormat for Cbor {
+    const ID: u8 = 2;
+
+    fn encode<T: Serialize>(&self, value: &T) -> anyhow::Result<Vec<u8>> {
+        let mut out = vec![];
+        ciborium::into_writer(value, &mut out)?;
+        Ok(out)
+    }
+
+    fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> anyhow::Result<T> {
+        Ok(ciborium::from_reader(bytes)?)
+    }
+}
+
+/// Trait for converting an interned value to and from the value written to a snapshot,
+///
+/// **Note** Values that contain intern handles are written w/ the local entropy removed and
+/// have the local entropy of the restoring thread applied when read.
+///
+pub(crate) trait SnapshotValue: Sized {
+    /// Value written to the snapshot,
+    ///
+    type Wire: Serialize + DeserializeOwned;
+
+    /// Converts into the value written to the snapshot,
+    ///
+    fn to_wire(&self) -> Self::Wire;
+
+    /// Converts from a value read from a snapshot,
+    ///
+    /// **Error** Returns an error if the value cannot be restored from the wire value.
+    ///
+    fn from_wire(wire: Self::Wire) -> anyhow::Result<Self>;
+}
+
+macro_rules! plain_snapshot_value {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl SnapshotValue for $ty {
+                type Wire = $ty;
+
+                fn to_wire(&self) -> Self::Wire {
+                    self.clone()
+                }
+
+                fn from_wire(wire: Self::Wire) -> anyhow::Result<Self> {
+                    Ok(wire)
+                }
+            }
+        )*
+    };
+}
+
+plain_snapshot_value!(
+    String,
+    bool,
+    usize,
+    u64,
+    Vec<String>,
+    BTreeMap<String, String>,
+    SourceSpan,
+    PathBuf,
+);
+
+impl SnapshotValue for bytes::Bytes {
+    type Wire = Vec<u8>;
+
+    fn to_wire(&self) -> Self::Wire {
+        self.to_vec()
+    }
+
+    fn from_wire(wire: Self::Wire) -> anyhow::Result<Self> {
+        Ok(bytes::Bytes::from(wire))
+    }
+}
+
+impl SnapshotValue for &'static str {
+    type Wire = String;
+
+    fn to_wire(&self) -> Self::Wire {
+        self.to_string()
+    }
+
+    /// **Note** Restored static strings are leaked, each distinct string is only leaked once so restoring the same
+    /// snapshot again does not leak, see `schema::leak`.
+    ///
+    /// **Error** Returns an error if leaking the string would exceed `MAX_LEAKED_BYTES`.
+    ///
+    fn from_wire(wire: Self::Wire) -> anyhow::Result<Self> {
+        crate::repr::schema::leak(&wire)
+    }
+}
+
+impl SnapshotValue for InternHandle {
+    type Wire = InternHandle;
+
+    fn to_wire(&self) -> Self::Wire {
+        self.canonical()
+    }
+
+    fn from_wire(wire: Self::Wire) -> anyhow::Result<Self> {
+        Ok(wire.with_entropy())
+    }
+}
+
+impl SnapshotValue for Repr {
+    type Wire = InternHandle;
+
+    fn to_wire(&self) -> Self::Wire {
+        self.tail.to_wire()
+    }
+
+    fn from_wire(wire: Self::Wire) -> anyhow::Result<Self> {
+        Ok(Repr {
+            tail: InternHandle::from_wire(wire)?,
+        })
+    }
+}
+
+impl SnapshotValue for Vec<Repr> {
+    type Wire = Vec<InternHandle>;
+
+    fn to_wire(&self) -> Self::Wire {
+        self.iter().map(Repr::to_wire).collect()
+    }
+
+    fn from_wire(wire: Self::Wire) -> anyhow::Result<Self> {
+        wire.into_iter().map(Repr::from_wire).collect()
+    }
+}
+
+/// Defines the registry of tables that are written to a snapshot,
+///
+/// **Note** Tables w/ values that cannot be serialized (i.e. TypeId, value parsers) are not included.
+///
+macro_rules! snapshot_tables {
+    ($($table:expr),* $(,)?) => {
+        /// Number of tables written to a snapshot,
+        ///
+        const TABLE_COUNT: u32 = [$(stringify!($table)),*].len() as u32;
+
+        /// Writes a frame for each table in the registry,
+        ///
+        fn write_tables(w: &mut impl Write, format: &impl ExportFormat) -> anyhow::Result<()> {
+            $(write_frame(w, format, &$table)?;)*
+            Ok(())
+        }
+
+        /// Returns true if a table w/ name is in the registry,
+        ///
+        fn is_known_table(name: &str) -> bool {
+            false $(|| name == $table.name())*
+        }
+
+        /// Reads the entries of a frame into the table w/ name,
+        ///
+        fn read_table(name: &str, entries: &[Vec<u8>], format: &impl ExportFormat) -> anyhow::Result<()> {
+            $(
+                if name == $table.name() {
+                    return read_frame(entries, format, &$table);
+                }
+            )*
+            Err(anyhow!("Unknown table {name}"))
+        }
+    };
+}
+
+snapshot_tables!(
+    HANDLES,
+    ENTITY,
+    INTERNER_FAMILY,
+    TYPE_NAME,
+    TYPE_SIZE,
+    PARSE_TYPE_NAME,
+    FFI_TYPE_NAME,
+    TRAIT_NAME,
+    OWNER_NAME,
+    OWNER_SIZE,
+    FIELD_OFFSET,
+    FIELD_NAME,
+    RECV_NAMES,
+    RECV_FIELDS,
+    RECV_CONTENT_ADDRESSED,
+    DEPENDENCY_NAME,
+    DEPENDENCY_PARENT,
+    VARIANT_NAME,
+    VARIANT_DISCRIMINANT,
+    SYMBOL,
+    INPUT,
+    TAG,
+    PATH,
+    NODE_IDX,
+    BLOCK_IDX,
+    SOURCE,
+    DOC_HEADERS,
+    ANNOTATIONS,
+    SOURCE_SPAN,
+    SOURCE_RELATIVE,
+    TIMESTAMP,
+    PAYLOAD,
+    ADDRESS,
+    EXTENSIONS,
+);
+
+/// Writes a snapshot of all intern tables encoded w/ bincode,
+///
+#[inline]
+pub fn snapshot<W: Write>(w: &mut W) -> anyhow::Result<()> {
+    snapshot_with(w, Bincode, Compression::None)
+}
+
+/// Writes a snapshot of all intern tables encoded w/ format and compressed w/ compression,
+///
+/// **Note** Handles are written w/ the local entropy removed, so a snapshot can be restored by a thread
+/// w/ a different entropy value.
+///
+pub fn snapshot_with<W: Write, F: ExportFormat>(
+    w: &mut W,
+    format: F,
+    compression: Compression,
+) -> anyhow::Result<()> {
+    w.write_all(&MAGIC)?;
+    w.write_all(&[VERSION, F::ID, compression.id()])?;
+    w.write_all(&TABLE_COUNT.to_le_bytes())?;
+
+    compression.write_tables(w, &format)
+}
+
+/// Default maximum size of a single encoded entry when restoring a snapshot,
+///
+pub const DEFAULT_MAX_ENTRY_BYTES: usize = 16 * 1024 * 1024;
+
+/// Default maximum number of entries in a single table frame when restoring a snapshot,
+///
+pub const DEFAULT_MAX_ENTRIES: usize = 16 * 1024 * 1024;
+
+/// Limits applied to the length prefixes read from a snapshot,
+///
+#[derive(Debug, Clone, Copy)]
+struct Limits {
+    /// Maximum size of a single encoded entry,
+    ///
+    max_entry_bytes: usize,
+    /// Maximum number of entries in a single table frame,
+    ///
+    max_entries: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_entry_bytes: DEFAULT_MAX_ENTRY_BYTES,
+            max_entries: DEFAULT_MAX_ENTRIES,
+        }
+    }
+}
+
+/// Restores a snapshot, detecting the format from the snapshot header,
+///
+/// **Note** Entries are assigned w/ the local entropy of the current thread applied.
+///
+#[inline]
+pub fn restore<R: Read>(r: &mut R) -> anyhow::Result<()> {
+    restore_with_limits(r, DEFAULT_MAX_ENTRY_BYTES, DEFAULT_MAX_ENTRIES)
+}
+
+/// Restores a snapshot, detecting the format from the snapshot header and validating each length prefix,
+///
+/// **Error** Returns an error if an entry is larger than `max_entry_bytes` or if a table frame declares more
+/// than `max_entries` entries, before any space is allocated for the entries.
+///
+pub fn restore_with_limits<R: Read>(
+    r: &mut R,
+    max_entry_bytes: usize,
+    max_entries: usize,
+) -> anyhow::Result<()> {
+    let limits = Limits {
+        max_entry_bytes,
+        max_entries,
+    };
+
+    let header = read_header(r)?;
+    let mut r = Compression::decoder(header.compression, r)?;
+
+    match header.format {
+        Bincode::ID => read_tables(&mut r, &header, &Bincode, limits),
+        #[cfg(feature = "cbor")]
+        Cbor::ID => read_tables(&mut r, &header, &Cbor, limits),
+        format => Err(anyhow!("Unsupported snapshot format {format}")),
+    }
+}
+
+/// Restores a snapshot that was written w/ format,
+///
+/// **Error** Returns an error if the snapshot header records a different format.
+///
+pub fn restore_with<R: Read, F: ExportFormat>(r: &mut R, format: F) -> anyhow::Result<()> {
+    let header = read_header(r)?;
+
+    if header.format != F::ID {
+        Err(anyhow!(
+            "Snapshot was written w/ format {}, expected {}",
+            header.format,
+            F::ID
+        ))?;
+    }
+
+    let mut r = Compression::decoder(header.compression, r)?;
+    read_tables(&mut r, &header, &format, Limits::default())
+}
+
+/// Header of a snapshot,
+///
+struct Header {
+    /// Id of the export format,
+    ///
+    format: u8,
+    /// Id of the compression codec,
+    ///
+    compression: u8,
+    /// Number of table frames,
+    ///
+    count: u32,
+}
+
+/// Reads the snapshot header,
+///
+fn read_header(r: &mut impl Read) -> anyhow::Result<Header> {
+    let mut magic = [0; 4];
+    r.read_exact(&mut magic)?;
+    if magic != MAGIC {
+        Err(anyhow!("Not a snapshot"))?;
+    }
+
+    let mut version = [0; 2];
+    r.read_exact(&mut version)?;
+    let [version, format] = version;
+
+    if version != VERSION {
+        Err(anyhow!("Unsupported snapshot version {version}"))?;
+    }
+
+    let mut compression = [0; 1];
+    r.read_exact(&mut compression)?;
+
+    let mut count = [0; 4];
+    r.read_exact(&mut count)?;
+
+    Ok(Header {
+        format,
+        compression: compression[0],
+        count: u32::from_le_bytes(count),
+    })
+}
+
+/// Reads the table frames of a snapshot,
+///
+/// **Note** Frames of tables that are not in the registry (i.e. tables added by a newer version) are skipped w/ the
+/// length prefix of each entry, so that snapshots are forward compatible.
+///
+fn read_tables(
+    r: &mut impl Read,
+    header: &Header,
+    format: &impl ExportFormat,
+    limits: Limits,
+) -> anyhow::Result<()> {
+    for _ in 0..header.count {
+        let mut len = [0; 2];
+        r.read_exact(&mut len)?;
+        let mut name = vec![0; u16::from_le_bytes(len) as usize];
+        r.read_exact(&mut name)?;
+        let name = String::from_utf8(name)?;
+
+        let mut len = [0; 4];
+        r.read_exact(&mut len)?;
+        let len = u32::from_le_bytes(len) as usize;
+        if len > limits.max_entries {
+            Err(anyhow!(
+                "Table {name} declares {len} entries, which exceeds the limit of {}",
+                limits.max_entries
+            ))?;
+        }
+
+        if !is_known_table(&name) {
+            warn!("Skipping unknown table {name} in snapshot");
+            for _ in 0..len {
+                let mut entry_len = [0; 4];
+                r.read_exact(&mut entry_len)?;
+                let entry_len = u32::from_le_bytes(entry_len) as u64;
+
+                let skipped = std::io::copy(&mut r.by_ref().take(entry_len), &mut std::io::sink())?;
+                if skipped != entry_len {
+                    Err(anyhow!("Unexpected end of snapshot in table {name}"))?;
+                }
+            }
+            continue;
+        }
+
+        let mut entries = vec![];
+        for _ in 0..len {
+            let mut entry_len = [0; 4];
+            r.read_exact(&mut entry_len)?;
+            let entry_len = u32::from_le_bytes(entry_len) as usize;
+            if entry_len > limits.max_entry_bytes {
+                Err(anyhow!(
+                    "Entry in table {name} declares {entry_len} bytes, which exceeds the limit of {}",
+                    limits.max_entry_bytes
+                ))?;
+            }
+
+            let mut entry = vec![0; entry_len];
+            r.read_exact(&mut entry)?;
+            entries.push(entry);
+        }
+
+        read_table(&name, &entries, format)?;
+    }
+
+    Ok(())
+}
+
+/// Writes a frame containing all entries of a table,
+///
+/// Frames are laid out as `name_len: u16 | name | entry_count: u32 | (entry_len: u32 | entry)*`.
+///
+fn write_frame<T>(
+    w: &mut impl Write,
+    format: &impl ExportFormat,
+    table: &InternTable<T>,
+) -> anyhow::Result<()>
+where
+    T: SnapshotValue + Send + Sync + 'static,
+{
+    let entries = table.entries();
+
+    let name = table.name().as_bytes();
+    w.write_all(&u16::try_from(name.len())?.to_le_bytes())?;
+    w.write_all(name)?;
+    w.write_all(&u32::try_from(entries.len())?.to_le_bytes())?;
+
+    for (handle, value) in entries {
+        let entry = format.encode(&(handle.canonical(), value.to_wire()))?;
+        w.write_all(&u32::try_from(entry.len())?.to_le_bytes())?;
+        w.write_all(&entry)?;
+    }
+
+    Ok(())
+}
+
+/// Decodes the entries of a frame and assigns each entry to table,
+///
+fn read_frame<T>(
+    entries: &[Vec<u8>],
+    format: &impl ExportFormat,
+    table: &InternTable<T>,
+) -> anyhow::Result<()>
+where
+    T: SnapshotValue + Send + Sync + 'static,
+{
+    for entry in entries {
+        let (handle, value): (InternHandle, T::Wire) = format.decode(entry)?;
+        table.assign_intern(handle.with_entropy(), T::from_wire(value)?)?;
+    }
+
+    Ok(())
+}
+
+#[allow(unused)]
+mod tests {
+    use crate::entropy::ENTROPY;
+    use crate::prelude::*;
+
+    use crate::macros::test_field;
+    use crate::repr::Repr;
+
+    struct Snapshot;
+
+    test_field!(Snapshot, 0, "snapshot");
+
+    /// Builds a repr w/ entropy and returns the snapshot and the canonical tail of the repr,
+    ///
+    #[cfg(feature = "crc-interner")]
+    fn build(
+        entropy: u64,
+        address: &'static str,
+        snapshot: fn(&mut Vec<u8>) -> anyhow::Result<()>,
+    ) -> (Vec<u8>, InternHandle) {
+        std::thread::spawn(move || {
+            ENTROPY.set(entropy);
+
+            let mut linker = Snapshot::linker::<CrcInterner>().unwrap();
+            linker
+                .push_level(NodeLevel::new().with_input("snapshot"))
+                .unwrap();
+            linker.push_level(HostLevel::new(address)).unwrap();
+            let repr = linker.link().unwrap();
+
+            let mut out = vec![];
+            snapshot(&mut out).unwrap();
+            (out, repr.tail.canonical())
+        })
+        .join()
+        .unwrap()
+    }
+
+    /// Restores a snapshot in a fresh thread w/ entropy and asserts the repr resolves,
+    ///
+    fn assert_restored(
+        entropy: u64,
+        address: &'static str,
+        tail: InternHandle,
+        restore: impl FnOnce() -> anyhow::Result<()> + Send + 'static,
+    ) {
+        std::thread::spawn(move || {
+            ENTROPY.set(entropy);
+
+            restore().unwrap();
+
+            let repr = Repr {
+                tail: tail.with_entropy(),
+            };
+            assert_eq!(
+                Some("alloc::string::String"),
+                repr.as_resource().and_then(|r| r.type_name())
+            );
+            assert_eq!(Some("snapshot"), repr.as_field().and_then(|f| f.name()));
+            assert_eq!(
+                "snapshot",
+                repr.as_node().and_then(|n| n.input()).unwrap().as_str()
+            );
+            assert_eq!(
+                address,
+                repr.as_host().and_then(|h| h.address()).unwrap().as_str()
+            );
+        })
+        .join()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let (snapshot, tail) = build(0x1111, "test://snapshot", super::snapshot);
+
+        assert_restored(0x2222, "test://snapshot", tail, move || {
+            super::restore_with(&mut snapshot.as_slice(), super::Bincode)
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "cbor")]
+    fn test_snapshot_cbor() {
+        let (snapshot, tail) = build(0x3333, "test://snapshot-cbor", |w| {
+            super::snapshot_with(w, super::Cbor, super::Compression::None)
+        });
+
+        let mismatched = snapshot.clone();
+        assert!(super::restore_with(&mut mismatched.as_slice(), super::Bincode).is_err());
+
+        assert_restored(0x4444, "test://snapshot-cbor", tail, move || {
+            super::restore(&mut snapshot.as_slice())
+        });
+    }
+
+    /// Writes a snapshot header and a frame header for the HANDLES table,
+    ///
+    fn corrupt_header(entries: u32) -> Vec<u8> {
+        let mut out = vec![];
+        out.extend_from_slice(b"RNIR");
+        out.extend_from_slice(&[super::VERSION, super::Bincode::ID, 0]);
+        out.extend_from_slice(&1u32.to_le_bytes());
+        out.extend_from_slice(&7u16.to_le_bytes());
+        out.extend_from_slice(b"HANDLES");
+        out.extend_from_slice(&entries.to_le_bytes());
+        out
+    }
+
+    #[test]
+    fn test_restore_with_limits() {
+        let mut absurd_entry = corrupt_header(1);
+        absurd_entry.extend_from_slice(&u32::MAX.to_le_bytes());
+        let err = super::restore_with_limits(&mut absurd_entry.as_slice(), 1024, 16).unwrap_err();
+        assert!(err.to_string().contains("exceeds the limit of 1024"));
+
+        let absurd_count = corrupt_header(u32::MAX);
+        let err = super::restore_with_limits(&mut absurd_count.as_slice(), 1024, 16).unwrap_err();
+        assert!(err.to_string().contains("exceeds the limit of 16"));
+
+        // Lengths within the limits that run past the end of the snapshot are an error
+        let mut truncated = corrupt_header(1);
+        truncated.extend_from_slice(&512u32.to_le_bytes());
+        assert!(super::restore(&mut truncated.as_slice()).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "zstd")]
+    fn test_snapshot_zstd() {
+        let source = "+ .operation compressed\n".repeat(4096);
+
+        let (uncompressed, compressed, tail) = std::thread::spawn(move || {
+            ENTROPY.set(0x5555);
+
+            let mut linker = Snapshot::linker::<CrcInterner>().unwrap();
+            linker
+                .push_level(
+                    NodeLevel::new()
+                        .with_input("compressed")
+                        .with_source(source),
+                )
+                .unwrap();
+            let repr = linker.link().unwrap();
+
+            let mut uncompressed = vec![];
+            super::snapshot(&mut uncompressed).unwrap();
+
+            let mut compressed = vec![];
+            super::snapshot_with(&mut compressed, super::Bincode, super::Compression::Zstd)
+                .unwrap();
+
+            (uncompressed, compressed, repr.tail.canonical())
+        })
+        .join()
+        .unwrap();
+
+        assert!(compressed.len() * 10 < uncompressed.len());
+
+        std::thread::spawn(move || {
+            ENTROPY.set(0x6666);
+
+            super::restore(&mut compressed.as_slice()).unwrap();
+
+            let repr = Repr {
+                tail: tail.with_entropy(),
+            };
+            let node = repr.as_node().unwrap();
+            assert_eq!("compressed", node.input().unwrap().as_str());
+            assert_eq!(
+                "+ .operation compressed\n".repeat(4096),
+                node.source().unwrap().as_str()
+            );
+        })
+        .join()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_restore_static_str() {
+        use super::SnapshotValue;
+
+        // Restoring an equal string again does not leak another copy
+        let restored = <&'static str>::from_wire(String::from("test_restore_static_str")).unwrap();
+        assert!(std::ptr::eq(
+            restored,
+            <&'static str>::from_wire(String::from("test_restore_static_str")).unwrap()
+        ));
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_restore_skips_unknown_table() {
+        use super::ExportFormat;
+
+        let frame = |out: &mut Vec<u8>, name: &str, entries: &[Vec<u8>]| {
+            out.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            out.extend_from_slice(name.as_bytes());
+            out.extend_from_slice(&(entries.len() as u32).to_le_bytes());
+            for entry in entries {
+                out.extend_from_slice(&(entry.len() as u32).to_le_bytes());
+                out.extend_from_slice(entry);
+            }
+        };
+
+        let handle = InternHandle::from_parts(0, 0x0400 | 0x25, 0x0600, 0);
+
+        let mut out = vec![];
+        out.extend_from_slice(b"RNIR");
+        out.extend_from_slice(&[super::VERSION, super::Bincode::ID, 0]);
+        out.extend_from_slice(&2u32.to_le_bytes());
+        frame(&mut out, "FUTURE_TABLE", &[vec![0xff; 13], vec![0xee; 7]]);
+        frame(
+            &mut out,
+            "INPUT",
+            &[super::Bincode
+                .encode(&(handle, String::from("forward compatible")))
+                .unwrap()],
+        );
+
+        // Handles are restored w/ the entropy of the current thread applied
+        ENTROPY.set(0);
+
+        super::restore(&mut out.as_slice()).unwrap();
+        assert_eq!("forward compatible", handle.input().unwrap().as_str());
+
+        assert!(logs_contain("Skipping unknown table FUTURE_TABLE"));
+    }
+}
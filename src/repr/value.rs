@@ -0,0 +1,119 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use anyhow::anyhow;
+
+use crate::prelude::*;
+
+/// Trait for resolving a repr into the concrete value it describes,
+///
+pub trait ResolveValue {
+    /// Resolves the value stored for repr,
+    ///
+    /// **Note** Returns None if a value was not stored for repr, or if the resource of repr is not `T`.
+    ///
+    fn resolve_value<T: Send + Sync + 'static>(&self, repr: &Repr) -> Option<Arc<T>>;
+}
+
+/// Registry of values keyed by the repr describing each value,
+///
+#[derive(Default)]
+pub struct ValueRegistry {
+    /// Map of stored values,
+    ///
+    values: RwLock<BTreeMap<Repr, Arc<dyn Any + Send + Sync>>>,
+}
+
+impl ValueRegistry {
+    /// Returns a new empty value registry,
+    ///
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores a value for repr, replacing any previously stored value,
+    ///
+    /// **Error** Returns an error if the resource of repr is not `T`.
+    ///
+    pub fn insert<T: Send + Sync + 'static>(&self, repr: Repr, value: T) -> anyhow::Result<()> {
+        if !repr.as_resource().is_some_and(|r| r.is_type::<T>()) {
+            Err(anyhow!(
+                "Resource type of {:x?} is not {}",
+                repr,
+                std::any::type_name::<T>()
+            ))?;
+        }
+
+        self.values
+            .write()
+            .map_err(|_| anyhow!("Value registry lock is poisoned"))?
+            .insert(repr, Arc::new(value));
+        Ok(())
+    }
+
+    /// Removes the value stored for repr,
+    ///
+    pub fn remove(&self, repr: &Repr) -> Option<Arc<dyn Any + Send + Sync>> {
+        self.values.write().ok()?.remove(repr)
+    }
+
+    /// Returns the number of values stored,
+    ///
+    pub fn len(&self) -> usize {
+        self.values.read().map(|v| v.len()).unwrap_or_default()
+    }
+
+    /// Returns true if no values are stored,
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl ResolveValue for ValueRegistry {
+    fn resolve_value<T: Send + Sync + 'static>(&self, repr: &Repr) -> Option<Arc<T>> {
+        if !repr.as_resource()?.is_type::<T>() {
+            return None;
+        }
+
+        let value = self.values.read().ok()?.get(repr).cloned()?;
+        value.downcast::<T>().ok()
+    }
+}
+
+#[allow(unused)]
+mod tests {
+    use crate::prelude::*;
+
+    use crate::macros::test_field;
+
+    struct Test;
+
+    test_field!(Test, 0, "value");
+
+    #[test]
+    fn test_resolve_value() {
+        let repr = Test::linker::<CrcInterner>().unwrap().link().unwrap();
+
+        let registry = ValueRegistry::new();
+        registry.insert(repr, String::from("resolved")).unwrap();
+        assert_eq!(1, registry.len());
+
+        let value = registry.resolve_value::<String>(&repr).unwrap();
+        assert_eq!("resolved", value.as_str());
+
+        // Mismatched types are not resolved or stored
+        assert!(registry.resolve_value::<u64>(&repr).is_none());
+        assert!(registry.insert(repr, 0u64).is_err());
+
+        // Reprs w/o a stored value are not resolved
+        let missing = Linker::new_crc::<String>().link().unwrap();
+        assert!(registry.resolve_value::<String>(&missing).is_none());
+
+        assert!(registry.remove(&repr).is_some());
+        assert!(registry.is_empty());
+    }
+}
@@ -0,0 +1,125 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::define_intern_table;
+use crate::prelude::*;
+use crate::push_tag;
+
+// Intern table for enum variant names
+define_intern_table!(VARIANT_NAME: String);
+
+// Intern table for enum variant discriminants
+define_intern_table!(VARIANT_DISCRIMINANT: u64);
+
+/// Variant level contains tags identifying the active variant of an enum resource,
+///
+pub struct VariantLevel {
+    /// Name of the variant,
+    ///
+    name: Tag<String, Arc<String>>,
+    /// Discriminant of the variant,
+    ///
+    discriminant: Tag<u64, Arc<u64>>,
+}
+
+impl VariantLevel {
+    /// Returns a new variant level w/ name and discriminant,
+    ///
+    #[inline]
+    pub fn new(name: &'static str, discriminant: u64) -> Self {
+        Self {
+            name: Tag::new(&VARIANT_NAME, Arc::new(name.to_string())),
+            discriminant: Tag::new(&VARIANT_DISCRIMINANT, Arc::new(discriminant)),
+        }
+    }
+}
+
+impl Level for VariantLevel {
+    fn configure(&self, interner: &mut impl InternerFactory) -> InternResult {
+        push_tag!(dyn interner, &self.name);
+        push_tag!(dyn interner, &self.discriminant);
+
+        interner.set_level_flags(LevelFlags::LEVEL_1);
+
+        interner.interner()
+    }
+
+    type Mount = (Arc<String>, u64);
+
+    #[inline]
+    fn mount(&self) -> Self::Mount {
+        (self.name.create_value.clone(), self.discriminant.value())
+    }
+}
+
+/// Wrapper struct with access to variant tags,
+///
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Serialize, Deserialize)]
+pub struct VariantRepr(pub(crate) InternHandle);
+
+impl VariantRepr {
+    /// Returns the repr whose tail is this level,
+    ///
+    /// **Note** If this level has not been linked, the returned repr only contains this level.
+    ///
+    #[inline]
+    pub fn to_repr(&self) -> Repr {
+        Repr::from_level(self.0)
+    }
+
+    /// Returns a variant level w/ the name and discriminant of this variant,
+    ///
+    /// **Note** Returns None if the name or discriminant of this variant could not be resolved.
+    ///
+    pub fn to_level(&self) -> Option<VariantLevel> {
+        Some(VariantLevel {
+            name: Tag::new(&VARIANT_NAME, self.name()?),
+            discriminant: Tag::new(&VARIANT_DISCRIMINANT, Arc::new(self.discriminant()?)),
+        })
+    }
+
+    /// Returns the name of this variant,
+    ///
+    #[inline]
+    pub fn name(&self) -> Option<Arc<String>> {
+        self.0.variant_name()
+    }
+
+    /// Returns the discriminant of this variant,
+    ///
+    #[inline]
+    pub fn discriminant(&self) -> Option<u64> {
+        self.0.variant_discriminant()
+    }
+}
+
+#[allow(unused)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_variant_level() {
+        struct Union;
+
+        let mut linker = Linker::new_crc::<Union>();
+        linker.push_level(VariantLevel::new("Some", 1)).unwrap();
+        let some = linker.link().unwrap();
+
+        let mut linker = Linker::new_crc::<Union>();
+        linker.push_level(VariantLevel::new("None", 0)).unwrap();
+        let none = linker.link().unwrap();
+
+        assert_ne!(some, none);
+        assert_eq!(ReprKind::Variant, some.kind());
+
+        let some = some.as_variant().unwrap();
+        assert_eq!("Some", some.name().unwrap().as_str());
+        assert_eq!(Some(1), some.discriminant());
+
+        let none = none.as_variant().unwrap();
+        assert_eq!("None", none.name().unwrap().as_str());
+        assert_eq!(Some(0), none.discriminant());
+    }
+}
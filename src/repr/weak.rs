@@ -0,0 +1,76 @@
+use crate::prelude::*;
+use crate::repr::HANDLES;
+
+/// Weak reference to a repr that is invalidated when any of its levels are removed from the `HANDLES` table,
+///
+/// **Note** Analogous to `std::sync::Weak`, a weak repr does not prevent the levels of a repr from being removed, and
+/// must be upgraded before the repr can be used.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WeakRepr {
+    /// Tail of the repr,
+    ///
+    tail: InternHandle,
+    /// Level handles of the repr, ordered from the root to the tail,
+    ///
+    levels: Vec<InternHandle>,
+}
+
+impl WeakRepr {
+    /// Returns the repr if every level of the repr is still linked,
+    ///
+    pub fn upgrade(&self) -> Option<Repr> {
+        if self.levels.is_empty() || !self.levels.iter().all(|l| HANDLES.contains(l)) {
+            return None;
+        }
+
+        Some(Repr { tail: self.tail })
+    }
+}
+
+impl Repr {
+    /// Returns a weak reference to this repr,
+    ///
+    /// **Note** The levels are resolved w/ the entropy of the current thread when the weak reference is created.
+    ///
+    pub fn downgrade_weak(&self) -> WeakRepr {
+        WeakRepr {
+            tail: self.tail,
+            levels: self.get_levels(),
+        }
+    }
+}
+
+#[allow(unused)]
+mod tests {
+    use crate::prelude::*;
+
+    use crate::macros::test_field;
+    use crate::repr::HANDLES;
+
+    struct Weak;
+
+    // Unique resource type, so that pruning the root does not affect other tests
+    struct WeakValue;
+
+    test_field!(Weak, 0, "weak", String, WeakValue, String);
+
+    #[test]
+    fn test_weak_repr() {
+        let mut linker = Weak::linker::<CrcInterner>().unwrap();
+        linker
+            .push_level(NodeLevel::new().with_input("weak repr"))
+            .unwrap();
+        let repr = linker.link().unwrap();
+
+        let weak = repr.downgrade_weak();
+        assert_eq!(Some(repr), weak.upgrade());
+
+        // Prune the root of the repr
+        let root = repr.get_levels()[0];
+        assert!(HANDLES.remove(&root).is_some());
+        assert!(HANDLES.remove(&root).is_none());
+
+        assert_eq!(None, weak.upgrade());
+    }
+}
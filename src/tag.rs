@@ -1,6 +1,9 @@
 use std::ops::Deref;
 use std::sync::Arc;
 
+use anyhow::Context;
+
+use crate::interner::INTERNER_FAMILY;
 use crate::prelude::*;
 use crate::repr::HANDLES;
 
@@ -26,6 +29,16 @@ impl<T: Send + Sync + 'static, F: Sync> Tag<T, F> {
             create_value,
         }
     }
+
+    /// Returns context for an error assigning this tag to a handle,
+    ///
+    fn assign_context(&self, handle: InternHandle) -> String {
+        format!(
+            "Could not assign {:x?} in table {}",
+            handle,
+            self.intern_table.name()
+        )
+    }
 }
 
 impl<T: Send + Sync + 'static> Tag<T> {
@@ -35,6 +48,7 @@ impl<T: Send + Sync + 'static> Tag<T> {
     pub fn assign(&self, handle: InternHandle) -> anyhow::Result<()> {
         self.intern_table
             .assign_intern(handle, (self.create_value)())
+            .with_context(|| self.assign_context(handle))
     }
 
     /// Returns the inner value,
@@ -52,6 +66,7 @@ impl<T: ToOwned<Owned = T> + Send + Sync + 'static> Tag<T, Arc<T>> {
     pub fn assign(&self, handle: InternHandle) -> anyhow::Result<()> {
         self.intern_table
             .assign_intern(handle, self.create_value.deref().to_owned())
+            .with_context(|| self.assign_context(handle))
     }
 
     /// Returns the inner value,
@@ -62,6 +77,24 @@ impl<T: ToOwned<Owned = T> + Send + Sync + 'static> Tag<T, Arc<T>> {
     }
 }
 
+impl<T: Copy + Send + Sync + 'static> Tag<T, T> {
+    /// Assigns a copy of a value known at runtime to an intern handle,
+    ///
+    #[inline]
+    pub fn assign(&self, handle: InternHandle) -> anyhow::Result<()> {
+        self.intern_table
+            .assign_intern(handle, self.create_value)
+            .with_context(|| self.assign_context(handle))
+    }
+
+    /// Returns the inner value,
+    ///
+    #[inline]
+    pub fn value(&self) -> T {
+        self.create_value
+    }
+}
+
 impl Tag<InternHandle, Arc<InternHandle>> {
     /// Creates and assigns an intern handle representing the link between the current intern handle and the
     /// next intern handle.
@@ -81,6 +114,16 @@ impl Tag<InternHandle, Arc<InternHandle>> {
             ))?;
         }
 
+        if let (Some(from_family), Some(to_family)) =
+            (INTERNER_FAMILY.copy(&from), INTERNER_FAMILY.copy(&to))
+        {
+            if from_family != to_family {
+                Err(anyhow::anyhow!(
+                    "Trying to link an intern handle created by {to_family} onto an intern handle created by {from_family}"
+                ))?;
+            }
+        }
+
         let link = from.register() ^ to.register();
 
         let mut out = *to.clone();
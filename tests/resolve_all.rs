@@ -0,0 +1,57 @@
+//! Interning reprs as values of a downstream intern table, the way a downstream crate would w/ `define_intern_table!`.
+
+use std::sync::Arc;
+
+use runir::define_intern_table;
+use runir::prelude::*;
+
+define_intern_table!(USER_REPR_REFS: Vec<Repr>);
+
+struct Resource;
+
+struct Test;
+
+impl Field<0> for Test {
+    type ParseType = String;
+    type ProjectedType = String;
+    type FFIType = String;
+
+    fn field_name() -> &'static str {
+        "resolved"
+    }
+}
+
+#[test]
+fn test_resolve_all() {
+    let resource = Linker::new_crc::<Resource>().link().unwrap();
+
+    let mut linker = Test::linker::<CrcInterner>().unwrap();
+    linker
+        .push_level(NodeLevel::new().with_input("resolved"))
+        .unwrap();
+    let node = linker.link().unwrap();
+
+    // A repr that was never linked will not resolve
+    let unlinked = Repr::from(0xdead_beef_0212_3456);
+
+    let handle = InternHandle::from_parts(0, 0x1234, 0x5678, 0);
+    let tag = Tag::new(&USER_REPR_REFS, Arc::new(vec![resource, node, unlinked]));
+    tag.assign(handle).unwrap();
+
+    let resolved = USER_REPR_REFS.resolve_all(&handle);
+    assert_eq!(2, resolved.len());
+
+    assert_eq!(resource, resolved[0].repr);
+    assert_eq!(ReprKind::Resource, resolved[0].kind);
+    assert_eq!(
+        Some(std::any::type_name::<Resource>()),
+        resolved[0].type_name
+    );
+
+    assert_eq!(node, resolved[1].repr);
+    assert_eq!(ReprKind::Node, resolved[1].kind);
+    assert_eq!(3, resolved[1].levels.len());
+    assert_eq!(Some("resolved"), resolved[1].field_name);
+    assert_eq!("resolved", resolved[1].input.as_ref().unwrap().as_str());
+    assert!(resolved[1].address.is_none());
+}
@@ -0,0 +1,34 @@
+//! `seal_all` seals every intern table in the process, so it is tested in its own test binary.
+
+use runir::prelude::*;
+
+struct Startup;
+
+struct AfterStartup;
+
+#[test]
+fn test_seal_all() {
+    let startup = Linker::new_crc::<Startup>().link().unwrap();
+
+    seal_all();
+
+    // Reprs linked before sealing can still be rebuilt
+    let relinked = Linker::new_crc::<Startup>().link().unwrap();
+    assert_eq!(startup, relinked);
+
+    // Linking a new repr is an error
+    let mut repr = Repr::default();
+    assert!(repr
+        .upgrade(CrcInterner::default(), ResourceLevel::new::<AfterStartup>())
+        .is_err());
+
+    unseal_all();
+
+    let mut repr = Repr::default();
+    repr.upgrade(CrcInterner::default(), ResourceLevel::new::<AfterStartup>())
+        .unwrap();
+    assert_eq!(
+        Some(std::any::type_name::<AfterStartup>()),
+        repr.as_resource().unwrap().type_name()
+    );
+}